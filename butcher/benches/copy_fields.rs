@@ -0,0 +1,99 @@
+//! Measures the time needed to butcher a borrowed struct whose fields all use
+//! the copy method, with and without copying the struct at once.
+//!
+//! Run with `cargo bench --bench copy_fields`.
+
+use std::borrow::Cow;
+use std::mem;
+use std::ptr;
+use std::time::Instant;
+
+use butcher::Butcher;
+
+const ITERATIONS: u32 = 10_000_000;
+
+// `Copy` is visible to the derive macro, so the struct is copied at once.
+#[derive(Butcher, Clone, Copy)]
+struct Vertex {
+    #[butcher(copy)]
+    x: f64,
+    #[butcher(copy)]
+    y: f64,
+    #[butcher(copy)]
+    z: f64,
+    #[butcher(copy)]
+    w: f64,
+    #[butcher(copy)]
+    id: u64,
+}
+
+// The same struct, but `Copy` is derived before `Butcher` and is not visible
+// to the derive macro, so each field is cloned.
+#[derive(Clone, Copy, Butcher)]
+struct FieldByFieldVertex {
+    #[butcher(copy)]
+    x: f64,
+    #[butcher(copy)]
+    y: f64,
+    #[butcher(copy)]
+    z: f64,
+    #[butcher(copy)]
+    w: f64,
+    #[butcher(copy)]
+    id: u64,
+}
+
+fn main() {
+    let vertex = Vertex {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+        id: 5,
+    };
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let ButcheredVertex { x, y, z, w, id } = Vertex::butcher(Cow::Borrowed(black_box(&vertex)));
+        black_box((x, y, z, w, id));
+    }
+    report("copied at once", start);
+
+    let vertex = FieldByFieldVertex {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+        id: 5,
+    };
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let ButcheredFieldByFieldVertex { x, y, z, w, id } =
+            FieldByFieldVertex::butcher(Cow::Borrowed(black_box(&vertex)));
+        black_box((x, y, z, w, id));
+    }
+    report("copied field by field", start);
+}
+
+/// Prevents the compiler from optimizing away the computation of `value`.
+///
+/// `std::hint::black_box` requires a more recent compiler than our MSRV.
+fn black_box<T>(value: T) -> T {
+    // SAFETY: `value` is forgotten, so the copy read here is its only owner.
+    unsafe {
+        let copy = ptr::read_volatile(&value);
+        mem::forget(value);
+        copy
+    }
+}
+
+fn report(name: &str, start: Instant) {
+    let elapsed = start.elapsed();
+    println!(
+        "{}: {:?} ({:?} per iteration)",
+        name,
+        elapsed,
+        elapsed / ITERATIONS
+    );
+}
@@ -53,12 +53,12 @@ where
 /// In order to call `as_deref` on a `Cow<T>`, the following requirements must be
 /// satisified:
 ///   - `T` must implement [`ToOwned`], which is required to build the initial
-///   `Cow<T>`,
+///     `Cow<T>`,
 ///   - `T` must implement [`Deref`], and its `Target` must also implement
-///   [`ToOwned`], which is required to build the output
-///   `Cow<<T as Deref>::Target>`, and when the borrowed case is met,
+///     [`ToOwned`], which is required to build the output
+///     `Cow<<T as Deref>::Target>`, and when the borrowed case is met,
 ///   - `T` must be convertible into the `Owned` type associated to the `Target`
-///   dereferenced type, which is required when the owned case is met.
+///     dereferenced type, which is required when the owned case is met.
 ///
 /// [`ToOwned`]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
 /// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
@@ -67,6 +67,7 @@ where
     T: Deref,
     <T as Deref>::Target: ToOwned,
 {
+    #[allow(clippy::wrong_self_convention)]
     fn as_deref(self) -> Cow<'cow, <T as Deref>::Target>;
 }
 
@@ -82,6 +83,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::owned_cow)]
 mod flatten_fn {
     use super::*;
 
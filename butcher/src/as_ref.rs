@@ -0,0 +1,132 @@
+//! Allows to convert the content of a `Cow` using the `AsRef` trait.
+//!
+//! This is similar to the `as_deref` module, but works for types which only
+//! implement [`AsRef`].
+//!
+//! [`AsRef`]: https://doc.rust-lang.org/std/convert/trait.AsRef.html
+
+use std::borrow::Cow;
+
+fn as_ref_cow<T, U>(input: Cow<T>) -> Cow<U>
+where
+    T: AsRef<U> + ToOwned + ?Sized,
+    U: ToOwned + ?Sized,
+    <U as ToOwned>::Owned: From<<T as ToOwned>::Owned>,
+{
+    match input {
+        Cow::Borrowed(input) => Cow::Borrowed(input.as_ref()),
+        Cow::Owned(input) => Cow::Owned(input.into()),
+    }
+}
+
+/// Allows to convert the content of a `Cow` using [`AsRef`].
+///
+/// This behaves like [`AsDerefCow`], but relies on [`AsRef`] instead of
+/// [`Deref`]. As such, it can be used for types which can be viewed as `U`
+/// without dereferencing to it.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use butcher::as_ref::AsRefCow;
+///
+/// #[derive(Clone)]
+/// struct Username(String);
+///
+/// impl AsRef<str> for Username {
+///     fn as_ref(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// impl From<Username> for String {
+///     fn from(name: Username) -> String {
+///         name.0
+///     }
+/// }
+///
+/// let name = Username(String::from("Grace Hopper"));
+///
+/// let borrowed: Cow<str> = Cow::Borrowed(&name).as_ref_cow();
+/// assert!(matches!(borrowed, Cow::Borrowed("Grace Hopper")));
+///
+/// let owned: Cow<str> = Cow::<Username>::Owned(name).as_ref_cow();
+/// assert!(matches!(owned, Cow::Owned(_)));
+/// assert_eq!(owned, "Grace Hopper");
+/// ```
+///
+/// # Traits requirements
+///
+/// In order to call `as_ref_cow` on a `Cow<T>`, the following requirements
+/// must be satisfied:
+///   - `T` must implement [`ToOwned`], which is required to build the initial
+///     `Cow<T>`,
+///   - `T` must implement `AsRef<U>`, and `U` must implement [`ToOwned`],
+///     which is required when the borrowed case is met,
+///   - the `Owned` type associated to `T` must be convertible into the `Owned`
+///     type associated to `U`, which is required when the owned case is met.
+///
+/// [`AsRef`]: https://doc.rust-lang.org/std/convert/trait.AsRef.html
+/// [`AsDerefCow`]: ../as_deref/trait.AsDerefCow.html
+/// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
+/// [`ToOwned`]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
+pub trait AsRefCow<'cow, U>
+where
+    U: ToOwned + ?Sized,
+{
+    #[allow(clippy::wrong_self_convention)]
+    fn as_ref_cow(self) -> Cow<'cow, U>;
+}
+
+impl<'cow, T, U> AsRefCow<'cow, U> for Cow<'cow, T>
+where
+    T: AsRef<U> + ToOwned + ?Sized,
+    U: ToOwned + ?Sized,
+    <U as ToOwned>::Owned: From<<T as ToOwned>::Owned>,
+{
+    fn as_ref_cow(self) -> Cow<'cow, U> {
+        as_ref_cow(self)
+    }
+}
+
+#[cfg(test)]
+mod as_ref_fn {
+    use super::*;
+
+    /// A type which implements `AsRef<str>`, but not `Deref<Target = str>`.
+    #[derive(Clone)]
+    struct Username(String);
+
+    impl AsRef<str> for Username {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl From<Username> for String {
+        fn from(name: Username) -> String {
+            name.0
+        }
+    }
+
+    #[test]
+    fn as_ref_cow_owned() {
+        let input: Cow<Username> = Cow::Owned(Username(String::from("foo")));
+        let output: Cow<str> = as_ref_cow(input);
+
+        assert!(matches!(output, Cow::Owned(_)));
+        assert_eq!(output, "foo");
+    }
+
+    #[test]
+    fn as_ref_cow_borrowed() {
+        let tmp = Username(String::from("bar"));
+        let input: Cow<Username> = Cow::Borrowed(&tmp);
+        let output: Cow<str> = as_ref_cow(input);
+
+        assert!(matches!(output, Cow::Borrowed(_)));
+        assert_eq!(output, "bar");
+    }
+}
@@ -52,6 +52,51 @@
 //! enum Foo<T> {
 //!     Bar(T),
 //! }
+//! # // Inline bounds, default values and where clauses must be supported.
+//! # #[derive(Clone, Butcher)]
+//! # enum Bounded<'a, T: Clone = u8>
+//! # where
+//! #     T: PartialEq,
+//! # {
+//! #     Bar(&'a T),
+//! #     Baz { #[butcher(as_deref)] elems: Vec<T> },
+//! # }
+//! # let _: ButcheredBounded = Bounded::butcher(std::borrow::Cow::Owned(Bounded::Bar(&1)));
+//! ```
+//!
+//! Variants may contain borrowed data. By default, a field of type `&'a str`
+//! becomes a `Cow<&'a str>`. Using the `copy` method keeps the `&'a str`, as
+//! copying a reference is cheap:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! enum Token<'a> {
+//!     Ident(#[butcher(copy)] &'a str),
+//!     Num(#[butcher(copy)] u64),
+//!     Raw(&'a str),
+//! }
+//!
+//! fn describe<'a>(token: &Token<'a>) -> String {
+//!     match Token::butcher(Cow::Borrowed(token)) {
+//!         ButcheredToken::Ident(ident) => {
+//!             // The initial lifetime is preserved.
+//!             let ident: &'a str = ident;
+//!             format!("ident {}", ident)
+//!         }
+//!         ButcheredToken::Num(num) => format!("num {}", num),
+//!         ButcheredToken::Raw(raw) => {
+//!             let raw: Cow<&'a str> = raw;
+//!             format!("raw {}", raw)
+//!         }
+//!     }
+//! }
+//!
+//! assert_eq!(describe(&Token::Ident("foo")), "ident foo");
+//! assert_eq!(describe(&Token::Num(42)), "num 42");
+//! assert_eq!(describe(&Token::Raw("bar")), "raw bar");
 //! ```
 //!
 //! Unbutchering can be used to create simple catch-all match arm:
@@ -85,3 +130,259 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Const generics
+//!
+//! Const generic parameters are carried to the butchered enum, and can be
+//! used in the payload of the variants:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! enum Packet<const N: usize> {
+//!     Fixed([u8; N]),
+//!     Var(Vec<u8>),
+//! }
+//!
+//! let fixed: Packet<4> = Packet::Fixed([1, 2, 3, 4]);
+//! let var: Packet<4> = Packet::Var(vec![5, 6]);
+//!
+//! match Packet::butcher(Cow::Borrowed(&fixed)) {
+//!     ButcheredPacket::Fixed(payload) => {
+//!         assert!(matches!(payload, Cow::Borrowed(&[1, 2, 3, 4])))
+//!     }
+//!     ButcheredPacket::Var(_) => unreachable!(),
+//! }
+//!
+//! match Packet::butcher(Cow::Owned(var)) {
+//!     ButcheredPacket::Var(payload) => assert!(matches!(payload, Cow::Owned(_))),
+//!     ButcheredPacket::Fixed(_) => unreachable!(),
+//! }
+//! # // The payloads must be unbutchered and reborrowed, whatever the method.
+//! # #[derive(Clone, Butcher)]
+//! # enum Frame<const N: usize> {
+//! #     Header { #[butcher(copy)] magic: [u8; N] },
+//! #     Body(#[butcher(as_deref)] Vec<[u8; N]>),
+//! # }
+//! # let header: Frame<2> = Frame::Header { magic: [7, 8] };
+//! # match Frame::unbutcher(Frame::butcher(Cow::Borrowed(&header))) {
+//! #     Frame::Header { magic } => assert_eq!(magic, [7, 8]),
+//! #     Frame::Body(_) => unreachable!(),
+//! # }
+//! # match Packet::unbutcher(Packet::butcher(Cow::Borrowed(&fixed))) {
+//! #     Packet::Fixed(payload) => assert_eq!(payload, [1, 2, 3, 4]),
+//! #     Packet::Var(_) => unreachable!(),
+//! # }
+//! ```
+//!
+//! ## Recursive enums
+//!
+//! The boxed fields of recursive enums can be butchered with the `rebutcher`
+//! method. `Butcher` is implemented for `Box<T>`, and returns the butchered
+//! form of `T` in a `Box`, so that the butchered enum is recursive as well.
+//! Each variant can use different methods:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! enum Expr {
+//!     Num(#[butcher(copy)] i64),
+//!     Add(#[butcher(rebutcher)] Box<Expr>, #[butcher(rebutcher)] Box<Expr>),
+//! }
+//!
+//! fn eval(expr: ButcheredExpr) -> i64 {
+//!     match expr {
+//!         ButcheredExpr::Num(value) => value,
+//!         ButcheredExpr::Add(left, right) => eval(*left) + eval(*right),
+//!     }
+//! }
+//!
+//! let expr = Expr::Add(Box::new(Expr::Num(1)), Box::new(Expr::Num(2)));
+//!
+//! match Expr::butcher(Cow::Borrowed(&expr)) {
+//!     ButcheredExpr::Add(left, right) => {
+//!         assert!(matches!(*left, ButcheredExpr::Num(1)));
+//!         assert!(matches!(*right, ButcheredExpr::Num(2)));
+//!     }
+//!     ButcheredExpr::Num(_) => unreachable!(),
+//! }
+//!
+//! assert_eq!(eval(Expr::butcher(Cow::Owned(expr))), 3);
+//! # // Nested expressions must be unbutchered as well.
+//! # let nested = Expr::Add(Box::new(Expr::Num(1)), Box::new(Expr::Add(Box::new(Expr::Num(2)), Box::new(Expr::Num(3)))));
+//! # match Expr::unbutcher(Expr::butcher(Cow::Borrowed(&nested))) {
+//! #     Expr::Add(_, right) => assert!(matches!(*right, Expr::Add(..))),
+//! #     Expr::Num(_) => unreachable!(),
+//! # }
+//! ```
+//!
+//! Chaining `unbox` and `rebutcher` can't be used here, as it would remove the
+//! `Box` from the butchered enum, whose size would be infinite.
+//!
+//! ## Checking the variant
+//!
+//! The `#[butcher(kind = "...")]` attribute generates a fieldless enum with the
+//! given name, which has the same variants as the initial enum. The `kind`
+//! method of the butchered enum returns the active variant, and
+//! `matches_variant` checks whether a given variant is active:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! #[butcher(kind = "WebEventKind")]
+//! enum WebEvent {
+//!     PageLoad,
+//!     KeyPress(char),
+//!     Click { x: i64, y: i64 },
+//! }
+//!
+//! let event = WebEvent::KeyPress('a');
+//! let butchered = WebEvent::butcher(Cow::Borrowed(&event));
+//!
+//! assert_eq!(butchered.kind(), WebEventKind::KeyPress);
+//! assert!(butchered.matches_variant(WebEventKind::KeyPress));
+//! assert!(!butchered.matches_variant(WebEventKind::Click));
+//! # // Generic enums and variants with named fields must be supported.
+//! # #[derive(Clone, Butcher)]
+//! # #[butcher(kind = "TreeKind")]
+//! # enum Tree<T> {
+//! #     Leaf(T),
+//! #     Node { kind: u8, children: Vec<Tree<T>> },
+//! # }
+//! # let tree = Tree::Node { kind: 0, children: vec![Tree::Leaf(1)] };
+//! # assert!(Tree::butcher(Cow::Owned(tree)).matches_variant(TreeKind::Node));
+//! # let click = WebEvent::Click { x: 0, y: 0 };
+//! # assert_eq!(WebEvent::butcher(Cow::Owned(click)).kind(), WebEventKind::Click);
+//! ```
+//!
+//! Nothing is generated without this attribute, so that the fieldless enum
+//! can't collide with an existing type.
+//!
+//! The `field_names` method returns the names of the fields of the active
+//! variant, in the order in which they are declared. The fields of tuple
+//! variants are named after their index:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! enum WebEvent {
+//!     PageLoad,
+//!     KeyPress(char),
+//!     Click { x: i64, y: i64 },
+//! }
+//!
+//! let click = WebEvent::Click { x: 4, y: 2 };
+//! assert_eq!(WebEvent::butcher(Cow::Borrowed(&click)).field_names(), &["x", "y"]);
+//!
+//! let key_press = WebEvent::KeyPress('a');
+//! assert_eq!(WebEvent::butcher(Cow::Borrowed(&key_press)).field_names(), &["0"]);
+//!
+//! let page_load = WebEvent::butcher(Cow::Owned(WebEvent::PageLoad));
+//! assert!(page_load.field_names().is_empty());
+//! # // A variant named `FieldNames` must not collide with the method.
+//! # #[derive(Clone, Butcher)]
+//! # enum Meta { FieldNames(u8) }
+//! # assert_eq!(Meta::butcher(Cow::Owned(Meta::FieldNames(1))).field_names(), &["0"]);
+//! ```
+//!
+//! ## Constructing butchered enums
+//!
+//! Each variant of the butchered enum gets a constructor, named after the
+//! variant in snake case. It takes the butchered fields, in the order in
+//! which they are declared. This is handy to test the code which consumes
+//! butchered enums:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Clone, Butcher)]
+//! enum WebEvent {
+//!     PageLoad,
+//!     KeyPress(char),
+//!     Click {
+//!         #[butcher(copy)]
+//!         x: i64,
+//!         #[butcher(copy)]
+//!         y: i64,
+//!     },
+//! }
+//!
+//! let page_load = ButcheredWebEvent::page_load();
+//! assert!(matches!(page_load, ButcheredWebEvent::PageLoad));
+//!
+//! let key_press = ButcheredWebEvent::key_press(Cow::Owned('a'));
+//! assert!(matches!(key_press, ButcheredWebEvent::KeyPress(Cow::Owned('a'))));
+//!
+//! let click = ButcheredWebEvent::click(4, 2);
+//! assert!(matches!(WebEvent::unbutcher(click), WebEvent::Click { x: 4, y: 2 }));
+//! # // Keywords, acronyms, variants colliding with the generated methods and
+//! # // variants colliding with each other must be supported.
+//! # #[derive(Clone, Butcher)]
+//! # #[butcher(kind = "TokenKind")]
+//! # enum Token<'a, T> {
+//! #     Type(&'a str),
+//! #     Crate,
+//! #     HTTPHeader { value: T },
+//! #     Kind,
+//! #     IOError(u8),
+//! #     IoError(u8),
+//! # }
+//! # let _: ButcheredToken<u8> = ButcheredToken::r#type(Cow::Borrowed(&"u8"));
+//! # let _: ButcheredToken<u8> = ButcheredToken::crate_();
+//! # let _: ButcheredToken<u8> = ButcheredToken::http_header(Cow::Owned(1));
+//! # assert!(ButcheredToken::<u8>::Kind.matches_variant(TokenKind::Kind));
+//! # assert!(ButcheredToken::<u8>::IOError(Cow::Owned(1)).matches_variant(TokenKind::IOError));
+//! # // Variants named `Kind` get a constructor when no kind enum is generated.
+//! # #[derive(Clone, Butcher)]
+//! # enum Sort { Kind(u8) }
+//! # assert!(matches!(ButcheredSort::kind(Cow::Owned(1)), ButcheredSort::Kind(_)));
+//! ```
+//!
+//! Variants whose names only differ by their case, such as `IOError` and
+//! `IoError`, would get constructors with the same name. None of them gets a
+//! constructor. The same goes for variants whose constructor would be named
+//! `field_names`, or `kind` and `matches_variant` when the fieldless enum is
+//! generated.
+//!
+//! The constructors are `const fn` when the enum has no type parameter, no
+//! lifetime parameter and no where clause. This allows to use them in
+//! constants:
+//!
+//! ```rust
+//! # use butcher::Butcher;
+//! # use std::borrow::Cow;
+//! #
+//! # #[derive(Clone, Butcher)]
+//! # enum WebEvent {
+//! #     PageLoad,
+//! #     KeyPress(char),
+//! #     Click {
+//! #         #[butcher(copy)]
+//! #         x: i64,
+//! #         #[butcher(copy)]
+//! #         y: i64,
+//! #     },
+//! # }
+//! #
+//! const ORIGIN_CLICK: ButcheredWebEvent<'static> = ButcheredWebEvent::click(0, 0);
+//! const ESCAPE: ButcheredWebEvent<'static> = ButcheredWebEvent::key_press(Cow::Borrowed(&'\x1b'));
+//!
+//! assert!(matches!(ORIGIN_CLICK, ButcheredWebEvent::Click { x: 0, y: 0 }));
+//! assert!(matches!(ESCAPE, ButcheredWebEvent::KeyPress(Cow::Borrowed('\x1b'))));
+//! # // Const generics must not prevent the constructors from being `const`.
+//! # #[derive(Clone, Butcher)]
+//! # enum Packet<const N: usize> {
+//! #     Fixed(#[butcher(copy)] [u8; N]),
+//! # }
+//! # const PACKET: ButcheredPacket<'static, 2> = ButcheredPacket::fixed([1, 2]);
+//! # assert!(matches!(PACKET, ButcheredPacket::Fixed([1, 2])));
+//! ```
@@ -118,7 +118,59 @@
 //!     # // The following field is hadded so that it is possible to track any
 //!     # // regression for https://github.com/scileo/butcher/issues/7
 //!     # cons: Box<Self>,
+//!     # // Fully qualified types must be handled just like imported ones.
+//!     # #[butcher(unbox)]
+//!     # e: Box<std::string::String>,
+//!     # f: std::collections::BTreeMap<usize, ::std::string::String>,
 //! }
+//! # fn check_qualified_paths(foo: std::borrow::Cow<Foo>) {
+//! #     let ButcheredFoo { e, f, .. } = Foo::butcher(foo);
+//! #     let _: std::borrow::Cow<String> = e;
+//! #     let _: std::borrow::Cow<std::collections::BTreeMap<usize, String>> = f;
+//! # }
+//! ```
+//!
+//! Butchering methods can be applied conditionally with `cfg_attr`. The
+//! attribute is expanded by the compiler before the derive macro runs:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Ticket {
+//!     // `id` is a `u32` when the `small_ids` feature is enabled, and a
+//!     // `Cow<u32>` otherwise.
+//!     #[cfg_attr(feature = "small_ids", butcher(copy))]
+//!     id: u32,
+//! }
+//!
+//! let ticket = Ticket { id: 42 };
+//! let ButcheredTicket { id } = Ticket::butcher(Cow::Borrowed(&ticket));
+//! assert_eq!(id.to_string(), "42");
+//! # // Check both an enabled and a disabled configuration predicate, on
+//! # // structs and on enums.
+//! # #[derive(Butcher, Clone)]
+//! # struct Conditional {
+//! #     #[cfg_attr(all(), butcher(copy))]
+//! #     enabled: u32,
+//! #     #[cfg_attr(any(), butcher(copy))]
+//! #     disabled: u32,
+//! #     #[cfg(any())]
+//! #     removed: u32,
+//! # }
+//! # let input = Conditional { enabled: 1, disabled: 2 };
+//! # let ButcheredConditional { enabled, disabled } = Conditional::butcher(Cow::Borrowed(&input));
+//! # let _: u32 = enabled;
+//! # let _: Cow<u32> = disabled;
+//! # #[derive(Butcher, Clone)]
+//! # enum ConditionalEnum {
+//! #     Foo(#[cfg_attr(all(), butcher(copy))] u32, #[cfg_attr(any(), butcher(copy))] u32),
+//! # }
+//! # let input = ConditionalEnum::Foo(1, 2);
+//! # let ButcheredConditionalEnum::Foo(enabled, disabled) = ConditionalEnum::butcher(Cow::Borrowed(&input));
+//! # let _: u32 = enabled;
+//! # let _: Cow<u32> = disabled;
 //! ```
 //!
 //! ## Regular
@@ -137,6 +189,197 @@
 //! In the previous example, the field `age` of `Client` may be marked as
 //! `copy`.
 //!
+//! When every field of a struct is marked as `copy` and the struct is
+//! [`Copy`][std-copy], as explained [below](#copy-structs), a borrowed struct
+//! is copied at once, instead of cloning each field. Its `Clone`
+//! implementation is never called.
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher)]
+//! #[derive(Clone, Copy)]
+//! struct Rgb {
+//!     #[butcher(copy)]
+//!     r: u8,
+//!     #[butcher(copy)]
+//!     g: u8,
+//!     #[butcher(copy)]
+//!     b: u8,
+//! }
+//!
+//! let color = Rgb { r: 255, g: 127, b: 0 };
+//!
+//! let ButcheredRgb { r, g, b } = Rgb::butcher(Cow::Borrowed(&color));
+//! assert_eq!((r, g, b), (255, 127, 0));
+//!
+//! let ButcheredRgb { r, g, b } = Rgb::butcher(Cow::Owned(color));
+//! assert_eq!((r, g, b), (255, 127, 0));
+//! # // Tuple structs, generics and structs which are not `Copy` must be
+//! # // supported as well.
+//! # #[derive(Butcher, Clone)]
+//! # struct Labelled<T>(#[butcher(copy)] String, #[butcher(copy)] T);
+//! # let labelled = Labelled("answer".to_string(), 42_u32);
+//! # let ButcheredLabelled(label, value) = Labelled::butcher(Cow::Borrowed(&labelled));
+//! # assert_eq!((label.as_str(), value), ("answer", 42));
+//! # let ButcheredLabelled(label, value) = Labelled::butcher(Cow::Owned(labelled));
+//! # assert_eq!((label.as_str(), value), ("answer", 42));
+//! # #[derive(Butcher, Clone, Copy)]
+//! # struct Shadowing { #[butcher(copy)] this: u8 }
+//! # let ButcheredShadowing { this } = Shadowing::butcher(Cow::Borrowed(&Shadowing { this: 1 }));
+//! # assert_eq!(this, 1);
+//! # // The struct must be copied, without calling any `Clone` implementation.
+//! # #[derive(Copy)]
+//! # struct Bits(u8);
+//! # impl Clone for Bits {
+//! #     fn clone(&self) -> Bits {
+//! #         panic!("the field was cloned")
+//! #     }
+//! # }
+//! # #[derive(Butcher)]
+//! # #[derive(Copy)]
+//! # struct NoClone { #[butcher(copy)] value: Bits }
+//! # impl Clone for NoClone {
+//! #     fn clone(&self) -> NoClone {
+//! #         panic!("the struct was cloned")
+//! #     }
+//! # }
+//! # let ButcheredNoClone { value } = NoClone::butcher(Cow::Borrowed(&NoClone { value: Bits(1) }));
+//! # assert_eq!(value.0, 1);
+//! ```
+//!
+//! The fields whose type is one of the small `Copy` types of the standard
+//! library use the `copy` method by default. These types are `Duration`,
+//! `Instant`, `SystemTime`, `IpAddr`, `Ipv4Addr`, `Ipv6Addr`, `SocketAddr`,
+//! `SocketAddrV4` and `SocketAddrV6`. They are only recognized when they are
+//! written with their full path, starting with `std` or `core`, as an imported
+//! type may be a user-defined type with the same name:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//! use std::time::Duration;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Timeout {
+//!     name: String,
+//!     delay: std::time::Duration,
+//!     address: std::net::Ipv4Addr,
+//! }
+//!
+//! let timeout = Timeout {
+//!     name: "connect".to_string(),
+//!     delay: Duration::from_secs(3),
+//!     address: std::net::Ipv4Addr::LOCALHOST,
+//! };
+//!
+//! let ButcheredTimeout { name, delay, address } = Timeout::butcher(Cow::Borrowed(&timeout));
+//! let _: Cow<String> = name;
+//! let delay: Duration = delay;
+//! assert_eq!(delay, Duration::from_secs(3));
+//! assert!(address.is_loopback());
+//! # // An explicit method must still take precedence.
+//! # #[derive(Butcher, Clone)]
+//! # struct Explicit(#[butcher(regular)] std::time::Duration);
+//! # let ButcheredExplicit(delay) = Explicit::butcher(Cow::Owned(Explicit(Duration::from_secs(1))));
+//! # let _: Cow<Duration> = delay;
+//! # // A user-defined type with the same name must use the regular method.
+//! # mod user {
+//! #     use butcher::Butcher;
+//! #     use std::borrow::Cow;
+//! #
+//! #     #[derive(Clone, Debug, PartialEq)]
+//! #     pub struct Duration(pub String);
+//! #
+//! #     #[derive(Butcher, Clone)]
+//! #     pub struct UserDefined {
+//! #         pub delay: Duration,
+//! #     }
+//! #
+//! #     pub fn check() {
+//! #         let user_defined = UserDefined { delay: Duration(String::from("3s")) };
+//! #         let ButcheredUserDefined { delay } = UserDefined::butcher(Cow::Borrowed(&user_defined));
+//! #         assert_eq!(delay, Cow::Borrowed(&Duration(String::from("3s"))));
+//! #     }
+//! # }
+//! # user::check();
+//! ```
+//!
+//! ### Copy structs
+//!
+//! The fields of a struct marked with `#[butcher(copy)]` use the `copy`
+//! method by default. The struct must implement [`Copy`][std-copy]:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Copy, Clone, Butcher)]
+//! #[butcher(copy)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! let point = Point { x: 1.0, y: 2.0 };
+//!
+//! let ButcheredPoint { x, y } = Point::butcher(Cow::Borrowed(&point));
+//! let (x, y): (f64, f64) = (x, y);
+//! assert_eq!((x, y), (1.0, 2.0));
+//! ```
+//!
+//! As a convenience, this is also the case when `Copy` is derived in an
+//! attribute placed after `#[derive(Butcher)]`. The compiler only shows the
+//! derive macro the `#[derive(...)]` attributes which come after the one
+//! invoking it, so a `Copy` derived before `Butcher` is not detected.
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher)]
+//! #[derive(Clone, Copy)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! let point = Point { x: 1.0, y: 2.0 };
+//!
+//! let ButcheredPoint { x, y } = Point::butcher(Cow::Borrowed(&point));
+//! let (x, y): (f64, f64) = (x, y);
+//! assert_eq!((x, y), (1.0, 2.0));
+//! # // The option must work on enums, and user-defined `Copy` derives must
+//! # // not be detected.
+//! # #[derive(Clone, Copy, Butcher)]
+//! # #[butcher(copy)]
+//! # enum Shape { Circle(f64) }
+//! # let ButcheredShape::Circle(radius) = Shape::butcher(Cow::Owned(Shape::Circle(1.0)));
+//! # let _: f64 = radius;
+//! # mod user {
+//! #     pub use std::clone::Clone as Copy;
+//! # }
+//! # #[derive(Butcher)]
+//! # #[derive(user::Copy)]
+//! # struct NotCopy(String);
+//! # let ButcheredNotCopy(name) = NotCopy::butcher(Cow::Owned(NotCopy(String::new())));
+//! # let _: Cow<String> = name;
+//! # // The default method must still be overridable, on structs and on enums.
+//! # #[derive(Butcher)]
+//! # #[derive(Clone, Copy)]
+//! # struct Overridden(#[butcher(regular)] u8, u8);
+//! # let ButcheredOverridden(regular, copied) = Overridden::butcher(Cow::Owned(Overridden(1, 2)));
+//! # let _: (Cow<u8>, u8) = (regular, copied);
+//! # #[derive(Butcher)]
+//! # #[derive(Clone, Copy)]
+//! # enum Direction { Left(u8), Right { speed: u8 } }
+//! # match Direction::butcher(Cow::Owned(Direction::Left(3))) {
+//! #     ButcheredDirection::Left(speed) => { let _: u8 = speed; }
+//! #     ButcheredDirection::Right { speed } => { let _: u8 = speed; }
+//! # }
+//! ```
+//!
 //! See the documentation for [`Copy`] for more information.
 //!
 //! ## As Deref
@@ -146,6 +389,136 @@
 //! `Vec<T>` is owned. Here, using `as_deref` on a field whose type is `Vec<T>`
 //! will convert it into `Cow<[T]>`.
 //!
+//! It can be mixed with fields borrowing data for a lifetime of the struct.
+//! Such references are usually butchered with the `copy` method, as copying a
+//! reference is cheap:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct View<'a, T> {
+//!     #[butcher(as_deref)]
+//!     name: String,
+//!     #[butcher(copy)]
+//!     data: &'a [T],
+//! }
+//!
+//! let bytes = [1, 2, 3];
+//! let view: View<u8> = View { name: "bytes".to_string(), data: &bytes };
+//!
+//! let ButcheredView { name, data } = View::butcher(Cow::Borrowed(&view));
+//! let (name, data): (Cow<str>, &[u8]) = (name, data);
+//! assert!(matches!(name, Cow::Borrowed("bytes")));
+//! assert_eq!(data, [1, 2, 3]);
+//!
+//! let view = View::unbutcher(View::butcher(Cow::Owned(view)));
+//! assert_eq!(view.name, "bytes");
+//! assert!(std::ptr::eq(view.data, &bytes[..]));
+//! # // The regular method must work on references as well, and the butchered
+//! # // struct must be reborrowable.
+//! # #[derive(Butcher, Clone)]
+//! # struct RegularView<'a, T> {
+//! #     #[butcher(as_deref)]
+//! #     name: String,
+//! #     data: &'a [T],
+//! # }
+//! # let view = RegularView { name: "bytes".to_string(), data: &bytes[1..] };
+//! # let butchered = RegularView::butcher(Cow::Owned(view));
+//! # let reborrowed = butchered.reborrow();
+//! # assert!(matches!(reborrowed.name, Cow::Borrowed("bytes")));
+//! # assert_eq!(**reborrowed.data, [2, 3]);
+//! # assert_eq!(RegularView::unbutcher(butchered).data, [2, 3]);
+//! ```
+//!
+//! When `as_deref` is used on a field which is already a `Cow`, the nested
+//! `Cow` is flattened: a `Cow<'a, str>` field becomes a `Cow<'cow, str>`,
+//! rather than a `Cow<Cow<'a, str>>`. Data borrowed by an owned struct stays
+//! borrowed:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Note<'a> {
+//!     #[butcher(as_deref)]
+//!     text: Cow<'a, str>,
+//! }
+//!
+//! let note = Note { text: Cow::Borrowed("remember the milk") };
+//!
+//! let ButcheredNote { text } = Note::butcher(Cow::Owned(note));
+//! let text: Cow<str> = text;
+//! assert!(matches!(text, Cow::Borrowed("remember the milk")));
+//!
+//! let note = Note::unbutcher(ButcheredNote { text });
+//! assert_eq!(note.text, "remember the milk");
+//! # // Generic and fully-qualified `Cow`s, and chained methods must be
+//! # // supported as well.
+//! # #[derive(Butcher, Clone)]
+//! # struct Generic<'a, T: Clone> {
+//! #     #[butcher(as_deref)]
+//! #     data: std::borrow::Cow<'a, T>,
+//! #     #[butcher(as_deref, as_deref)]
+//! #     name: Cow<'a, String>,
+//! # }
+//! # let generic = Generic { data: Cow::Borrowed(&1_u8), name: Cow::Owned("a".to_string()) };
+//! # let ButcheredGeneric { data, name } = Generic::butcher(Cow::Owned(generic));
+//! # let (data, name): (Cow<u8>, Cow<str>) = (data, name);
+//! # assert!(matches!(data, Cow::Borrowed(1)));
+//! # assert!(matches!(name, Cow::Owned(_)));
+//! # let reborrowed = ButcheredGeneric { data, name };
+//! # assert_eq!(*reborrowed.reborrow().data, 1);
+//! ```
+//!
+//! The derive macro can't resolve imports, so a type written as `Cow` is
+//! assumed to be the `Cow` of the standard library. A user-defined type named
+//! `Cow` must be written with a path, such as `self::Cow`, to be butchered as
+//! any other type.
+//!
+//! The derive macro only sees the type of the fields as it is written, so it
+//! can't know that a type alias stands for a type such as `String`. The
+//! `as_deref_types` option lists type names which use the `as_deref` method
+//! by default. A field matches when the first segment of its type is one of
+//! these names, so aliases must be referred to by their name rather than by a
+//! path:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! type Name = String;
+//! type Text = String;
+//!
+//! #[derive(Butcher, Clone)]
+//! #[butcher(as_deref_types(Name, Text))]
+//! struct Post {
+//!     author: Name,
+//!     body: Text,
+//!     #[butcher(regular)]
+//!     title: Text,
+//! }
+//!
+//! let post = Post {
+//!     author: "ferris".to_string(),
+//!     body: "Hello!".to_string(),
+//!     title: "Greetings".to_string(),
+//! };
+//!
+//! let ButcheredPost { author, body, title } = Post::butcher(Cow::Borrowed(&post));
+//! let (author, body, _): (Cow<str>, Cow<str>, Cow<String>) = (author, body, title);
+//! assert!(matches!(author, Cow::Borrowed("ferris")));
+//! assert!(matches!(body, Cow::Borrowed("Hello!")));
+//! # // Enums must accept the option as well.
+//! # #[derive(Butcher, Clone)]
+//! # #[butcher(as_deref_types(Name))]
+//! # enum Message { Signed(Name, #[butcher(copy)] u8) }
+//! # let ButcheredMessage::Signed(name, _) = Message::butcher(Cow::Owned(Message::Signed("a".to_string(), 1)));
+//! # let _: Cow<str> = name;
+//! ```
+//!
 //! See the documentation for [`AsDeref`] for more information.
 //!
 //! ## Unbox
@@ -156,6 +529,180 @@
 //!
 //! See the documentation for [`Unbox`] for more information.
 //!
+//! ## Vec cow
+//!
+//! This method is used on fields whose type is `Vec<T>`. It returns a
+//! `Vec<Cow<T>>`, where each element is borrowed or owned depending on the
+//! initial struct. This differs from `as_deref`, which returns a `Cow<[T]>`.
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Playlist {
+//!     #[butcher(vec_cow)]
+//!     songs: Vec<String>,
+//!     #[butcher(as_deref)]
+//!     artists: Vec<String>,
+//! }
+//!
+//! let names = vec!["Blackbird".to_string(), "Yesterday".to_string(), "Help!".to_string()];
+//! let playlist = Playlist { songs: names.clone(), artists: names };
+//!
+//! let ButcheredPlaylist { songs, artists } = Playlist::butcher(Cow::Borrowed(&playlist));
+//! let songs: Vec<Cow<String>> = songs;
+//! let artists: Cow<[String]> = artists;
+//!
+//! assert_eq!(songs.len(), 3);
+//! assert!(songs.iter().all(|song| matches!(song, Cow::Borrowed(_))));
+//! assert!(matches!(artists, Cow::Borrowed(_)));
+//! assert!(songs.iter().map(AsRef::as_ref).eq(artists.iter()));
+//!
+//! let ButcheredPlaylist { songs, artists } = Playlist::butcher(Cow::Owned(playlist));
+//! assert!(songs.iter().all(|song| matches!(song, Cow::Owned(_))));
+//! assert!(matches!(artists, Cow::Owned(_)));
+//! assert!(songs.iter().map(AsRef::as_ref).eq(artists.iter()));
+//! # // The fields must be reborrowed and unbutchered element-wise.
+//! # let butchered = Playlist::butcher(Cow::Owned(Playlist::unbutcher(ButcheredPlaylist { songs, artists })));
+//! # assert!(butchered.reborrow().songs.iter().all(|song| matches!(song, Cow::Borrowed(_))));
+//! # assert_eq!(Playlist::unbutcher(butchered).songs[2], "Help!");
+//! ```
+//!
+//! See the documentation for [`FlattenVec`] for more information.
+//!
+//! ## Into owned
+//!
+//! This method is used on fields whose type is already a [`Cow`]. It always
+//! returns the owned form of the data, cloning it when it is borrowed. For
+//! instance, a field of type `Cow<str>` becomes a `String`.
+//!
+//! See the documentation for [`IntoOwned`] for more information.
+//!
+//! ## Snapshot
+//!
+//! This method is used on interior-mutable fields, such as `Cell<T>` or the
+//! atomic integers. It reads the value contained in the field, instead of
+//! returning a [`Cow`]. For instance, a field of type `AtomicU32` becomes a
+//! `u32`.
+//!
+//! See the documentation for [`Snapshot`] for more information.
+//!
+//! ## Skip
+//!
+//! This method discards the field, which is replaced by `()` in the butchered
+//! struct. It is useful for fields which can't be cloned, such as trait
+//! objects. The field is recreated with its [`Default`] implementation when
+//! unbutchering.
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher)]
+//! struct Job {
+//!     #[butcher(copy)]
+//!     id: u32,
+//!     #[butcher(skip)]
+//!     callback: Option<Box<dyn Fn(u32) -> u32>>,
+//! }
+//!
+//! impl Clone for Job {
+//!     // The callback can't be cloned, so it is dropped.
+//!     fn clone(&self) -> Job {
+//!         Job { id: self.id, callback: None }
+//!     }
+//! }
+//!
+//! let job = Job { id: 42, callback: Some(Box::new(|x| x + 1)) };
+//!
+//! let ButcheredJob { id, callback: () } = Job::butcher(Cow::Borrowed(&job));
+//! assert_eq!(id, 42);
+//!
+//! let job = Job::unbutcher(Job::butcher(Cow::Owned(job)));
+//! assert!(job.callback.is_none());
+//! # // Trait objects must not lead to bounds such as `dyn Any: Clone`.
+//! # use std::any::Any;
+//! # use std::rc::Rc;
+//! # #[derive(Butcher)]
+//! # struct Registry<T: 'static> {
+//! #     #[butcher(skip)]
+//! #     entries: Vec<Box<dyn Any>>,
+//! #     #[butcher(skip)]
+//! #     source: Option<Box<dyn Iterator<Item = T>>>,
+//! #     #[butcher(copy)]
+//! #     shared: Rc<dyn Any>,
+//! # }
+//! # impl<T> Clone for Registry<T> {
+//! #     fn clone(&self) -> Self {
+//! #         Registry { entries: Vec::new(), source: None, shared: Rc::clone(&self.shared) }
+//! #     }
+//! # }
+//! # let registry: Registry<u8> = Registry { entries: vec![Box::new(1)], source: None, shared: Rc::new(2) };
+//! # let ButcheredRegistry { entries: (), source: (), shared } = Registry::butcher(Cow::Borrowed(&registry));
+//! # assert!(Rc::ptr_eq(&shared, &registry.shared));
+//! ```
+//!
+//! Fields which don't implement [`Default`] can use
+//! `#[butcher(skip(default = Type))]`, where `Type` implements
+//! [`SkipDefault`] for the type of the field.
+//!
+//! See the documentation for [`Skip`] for more information.
+//!
+//! ## Weak
+//!
+//! This method is used on weak references, such as `std::rc::Weak<T>`. It
+//! upgrades the reference, and returns `Some(Rc<T>)` if the pointed value is
+//! still alive, or `None` otherwise. Unbutchering downgrades the pointer back.
+//!
+//! See the documentation for [`Weak`] for more information.
+//!
+//! ## Option ref
+//!
+//! This method is used on optional references, such as `Option<&'a T>`. It
+//! returns an `Option<Cow<'cow, T>>`, which borrows through the reference
+//! even if the struct is owned. The butchered struct can't be reborrowed, as
+//! the reference must live for `'cow`.
+//!
+//! See the documentation for [`OptionRef`] for more information.
+//!
+//! ## Maybe owned
+//!
+//! This method behaves like the regular method, but returns a [`MaybeOwned`]
+//! instead of a `Cow`. Its bounds only mention [`Clone`][std-clone], and never
+//! [`ToOwned`][std-to-owned]:
+//!
+//! ```rust
+//! use butcher::maybe_owned::MaybeOwned;
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Labelled<T: Clone> {
+//!     #[butcher(maybe_owned)]
+//!     value: T,
+//! }
+//!
+//! let labelled = Labelled { value: 'a' };
+//! let ButcheredLabelled { value } = Labelled::butcher(Cow::Borrowed(&labelled));
+//! assert!(matches!(value, MaybeOwned::Borrowed('a')));
+//! # // The butchered struct must be reborrowable.
+//! # let butchered = Labelled::butcher(Cow::Owned(labelled));
+//! # assert!(butchered.reborrow().value.is_borrowed());
+//! # assert_eq!(Labelled::unbutcher(butchered).value, 'a');
+//! ```
+//!
+//! See the documentation for [`MaybeOwnedMethod`] for more information.
+//!
+//! ## Transform
+//!
+//! This method applies a user-defined transform on the field. The transform
+//! is a type implementing [`ButcherTransform`], and is specified with
+//! `#[butcher(transform = MyTransform)]`. It can't be followed by another
+//! butchering method.
+//!
+//! See the documentation for [`ButcherTransform`] for more information.
+//!
 //! ## Rebutcher
 //!
 //! Sometimes it is necessary to butcher again a field of a butchered struct.
@@ -164,6 +711,266 @@
 //!
 //! See the documentation for [`Rebutcher`] for more information.
 //!
+//! Rebutchered fields can be mixed with fields using any other method. In
+//! the following example, the title is dereferenced while the body is
+//! butchered again:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Section {
+//!     heading: String,
+//!     #[butcher(copy)]
+//!     level: u8,
+//! }
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Doc {
+//!     #[butcher(as_deref)]
+//!     title: String,
+//!     #[butcher(rebutcher)]
+//!     body: Section,
+//! }
+//!
+//! let doc = Doc {
+//!     title: "Butchering".to_string(),
+//!     body: Section { heading: "Intro".to_string(), level: 1 },
+//! };
+//!
+//! let ButcheredDoc { title, body } = Doc::butcher(Cow::Borrowed(&doc));
+//! let ButcheredSection { heading, level } = body;
+//!
+//! assert!(matches!(title, Cow::Borrowed("Butchering")));
+//! assert!(matches!(heading, Cow::Borrowed(h) if h == "Intro"));
+//! assert_eq!(level, 1);
+//!
+//! let butchered = Doc::butcher(Cow::Owned(doc));
+//! let doc = Doc::unbutcher(butchered);
+//! assert_eq!(doc.title, "Butchering");
+//! assert_eq!(doc.body.heading, "Intro");
+//! # // Both methods must work in tuple structs as well.
+//! # #[derive(Butcher, Clone)]
+//! # struct Pair(#[butcher(as_deref)] Box<str>, #[butcher(rebutcher)] Section);
+//! # let pair = Pair("a".into(), Section { heading: "b".to_string(), level: 3 });
+//! # let ButcheredPair(a, b) = Pair::butcher(Cow::Borrowed(&pair));
+//! # assert!(matches!(a, Cow::Borrowed("a")));
+//! # assert_eq!(b.level, 3);
+//! # let pair = Pair::unbutcher(Pair::butcher(Cow::Owned(pair)));
+//! # assert_eq!(pair.1.heading, "b");
+//! ```
+//!
+//! ## Chaining methods
+//!
+//! Multiple butchering methods can be specified on a field. They are applied
+//! from left to right, each method being applied on the content of the `Cow`
+//! returned by the previous one. As such, only `regular`, `as_deref` and
+//! `unbox` can be followed by another method.
+//!
+//! For instance, a field of type `Box<String>` can be unboxed and then
+//! dereferenced, so that the butchered field has type `Cow<str>`:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Foo {
+//!     #[butcher(unbox, as_deref)]
+//!     name: Box<String>,
+//! }
+//!
+//! let foo = Foo { name: Box::new(String::from("Ada Lovelace")) };
+//!
+//! let ButcheredFoo { name } = Foo::butcher(Cow::Borrowed(&foo));
+//!
+//! let name: Cow<str> = name;
+//! assert_eq!(name, Cow::Borrowed("Ada Lovelace"));
+//! ```
+//!
+//! See the documentation for [`Chain`] for more information.
+//!
+//! ## Tuple structs
+//!
+//! Butchering methods can be specified on each field of a tuple struct as
+//! well:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Span(
+//!     #[butcher(copy)] usize,
+//!     #[butcher(copy)] usize,
+//!     #[butcher(as_deref)] String,
+//! );
+//!
+//! let span = Span(7, 12, "hello".to_string());
+//!
+//! let ButcheredSpan(start, end, text) = Span::butcher(Cow::Borrowed(&span));
+//!
+//! assert_eq!(start, 7_usize);
+//! assert_eq!(end, 12_usize);
+//! assert_eq!(text, Cow::Borrowed("hello"));
+//! ```
+//!
+//! When a tuple struct wraps a single collection, the butchered struct gets a
+//! `len` and an `is_empty` method. They are available when the butchered field
+//! implements [`Len`], whether it is borrowed or owned:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Ids(Vec<u64>);
+//!
+//! let ids = Ids(vec![4, 8, 15]);
+//!
+//! assert_eq!(Ids::butcher(Cow::Borrowed(&ids)).len(), 3);
+//! assert!(!Ids::butcher(Cow::Owned(ids)).is_empty());
+//! # // Other butchering methods, generics, and fields which are not
+//! # // collections must be supported.
+//! # #[derive(Butcher, Clone)]
+//! # struct Names<T: Clone>(#[butcher(as_deref)] Vec<T>);
+//! # assert!(Names::<u8>::butcher(Cow::Owned(Names(Vec::new()))).is_empty());
+//! # #[derive(Butcher, Clone)]
+//! # struct Id(u64);
+//! # let _ = Id::butcher(Cow::Owned(Id(1)));
+//! ```
+//!
+//! When the butchered field implements [`IntoVec`], the butchered struct gets
+//! an `into_inner_vec` method as well. It moves the elements out of an owned
+//! collection, and clones them only when the collection is borrowed:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static CLONES: AtomicUsize = AtomicUsize::new(0);
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Tracked(u64);
+//!
+//! impl Clone for Tracked {
+//!     fn clone(&self) -> Tracked {
+//!         CLONES.fetch_add(1, Ordering::SeqCst);
+//!         Tracked(self.0)
+//!     }
+//! }
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Ids(Vec<Tracked>);
+//!
+//! let ids = Ids(vec![Tracked(4), Tracked(8)]);
+//!
+//! let borrowed = Ids::butcher(Cow::Borrowed(&ids)).into_inner_vec();
+//! assert_eq!(borrowed, [Tracked(4), Tracked(8)]);
+//! assert_eq!(CLONES.load(Ordering::SeqCst), 2);
+//!
+//! let owned = Ids::butcher(Cow::Owned(ids)).into_inner_vec();
+//! assert_eq!(owned, [Tracked(4), Tracked(8)]);
+//! assert_eq!(CLONES.load(Ordering::SeqCst), 2);
+//! # // Fields which are not collections must still be supported.
+//! # #[derive(Butcher, Clone)]
+//! # struct Single(#[butcher(copy)] u8);
+//! # let _ = Single::butcher(Cow::Owned(Single(1)));
+//! ```
+//!
+//! ## Const generics
+//!
+//! Structs may declare const generics, which can be used in the type of
+//! their fields:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Buf<T, const N: usize> {
+//!     data: [T; N],
+//!     #[butcher(copy)]
+//!     len: usize,
+//! }
+//!
+//! let buf: Buf<u8, 4> = Buf { data: [1, 2, 3, 4], len: 2 };
+//!
+//! let ButcheredBuf { data, len } = Buf::butcher(Cow::Borrowed(&buf));
+//! assert!(matches!(data, Cow::Borrowed(&[1, 2, 3, 4])));
+//! assert_eq!(len, 2);
+//!
+//! let ButcheredBuf { data, len } = Buf::butcher(Cow::Owned(buf));
+//! assert!(matches!(data, Cow::Owned([1, 2, 3, 4])));
+//! assert_eq!(len, 2);
+//! # // Const generics may be used in generic arguments, or not be used at
+//! # // all by a field. Enums must support them as well. Default values are not
+//! # // tested, as they require rustc 1.59.
+//! # #[derive(Clone)]
+//! # struct Marker<const M: usize>;
+//! # #[derive(Butcher, Clone)]
+//! # struct Tagged<const N: usize, const UNUSED: bool>(Marker<N>, u8);
+//! # let ButcheredTagged(_, tag) = Tagged::<3, false>::butcher(Cow::Owned(Tagged(Marker, 1)));
+//! # assert_eq!(*tag, 1);
+//! # #[derive(Butcher, Clone)]
+//! # enum Chunk<T, const N: usize> {
+//! #     Full([T; N]),
+//! #     Partial(Vec<T>),
+//! # }
+//! # let chunk = Chunk::Full([1_u8, 2]);
+//! # assert!(matches!(Chunk::butcher(Cow::Borrowed(&chunk)), ButcheredChunk::Full(Cow::Borrowed(&[1, 2]))));
+//! # let butchered = Buf::butcher(Cow::Owned(Buf { data: [1_u8], len: 1 }));
+//! # assert_eq!(butchered.reborrow().to_owned_struct().data, [1]);
+//! ```
+//!
+//! ## Butchering owned data
+//!
+//! When the input is owned, the struct is destructured once, and each field is
+//! moved in the butchered struct. No data is cloned, even for fields using
+//! `copy`:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! static CLONES: AtomicUsize = AtomicUsize::new(0);
+//!
+//! // A type which counts how many times it is cloned.
+//! struct Counted(Vec<u8>);
+//!
+//! impl Clone for Counted {
+//!     fn clone(&self) -> Counted {
+//!         CLONES.fetch_add(1, Ordering::SeqCst);
+//!         Counted(self.0.clone())
+//!     }
+//! }
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Foo {
+//!     regular: Counted,
+//!     #[butcher(copy)]
+//!     copied: Counted,
+//!     #[butcher(unbox)]
+//!     boxed: Box<Counted>,
+//! }
+//!
+//! let foo = Foo {
+//!     regular: Counted(vec![1]),
+//!     copied: Counted(vec![2]),
+//!     boxed: Box::new(Counted(vec![3])),
+//! };
+//!
+//! let ButcheredFoo { regular, copied, boxed } = Foo::butcher(Cow::Owned(foo));
+//!
+//! assert!(matches!(regular, Cow::Owned(_)));
+//! assert_eq!(copied.0, [2]);
+//! assert!(matches!(boxed, Cow::Owned(_)));
+//! assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+//! ```
+//!
 //! ## Retrieving the initial input type
 //!
 //! The `unbutcher` allows to retrieve the initial data, in its owned form.
@@ -203,6 +1010,387 @@
 //! }
 //! ```
 //!
+//! The butchered struct also has a `to_owned_struct` method, which clones its
+//! fields and returns the initial struct, without consuming it:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Client {
+//!     #[butcher(as_deref)]
+//!     name: String,
+//!     #[butcher(copy)]
+//!     age: u8,
+//! }
+//!
+//! let client = Client {
+//!     name: "Ada Lovelace".to_string(),
+//!     age: 36,
+//! };
+//!
+//! let butchered = Client::butcher(Cow::Borrowed(&client));
+//! let owned: Client = butchered.to_owned_struct();
+//!
+//! assert_eq!(owned.name, "Ada Lovelace");
+//! assert_eq!(owned.age, 36);
+//!
+//! // The butchered struct is still borrowing the initial struct.
+//! assert!(matches!(butchered.name, Cow::Borrowed("Ada Lovelace")));
+//! # // Tuple structs and fields which can't be reborrowed must be supported.
+//! # #[derive(Butcher, Clone)]
+//! # struct Wrapper<T>(#[butcher(rebutcher)] Client, T);
+//! # let wrapper = Wrapper(client.clone(), 1_u8);
+//! # let butchered = Wrapper::butcher(Cow::Borrowed(&wrapper));
+//! # let owned = butchered.to_owned_struct();
+//! # assert_eq!(owned.0.name, "Ada Lovelace");
+//! # assert_eq!(owned.1, 1);
+//! ```
+//!
+//! The butchered struct can also be converted into a `Cow` of the initial
+//! struct. The fields can't be turned back into a reference to the initial
+//! struct, so the resulting `Cow` is always owned, even if every field is
+//! borrowed:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Client {
+//!     name: String,
+//!     #[butcher(copy)]
+//!     age: u8,
+//! }
+//!
+//! let client = Client {
+//!     name: "Ada Lovelace".to_string(),
+//!     age: 36,
+//! };
+//!
+//! let butchered = Client::butcher(Cow::Borrowed(&client));
+//! let cow: Cow<Client> = butchered.into();
+//!
+//! assert!(matches!(cow, Cow::Owned(Client { age: 36, .. })));
+//! # // Generic structs and enums must be supported as well.
+//! # #[derive(Butcher, Clone)]
+//! # struct Pair<'a, T: Clone>(&'a str, T);
+//! # let pair = Pair("a", 1_u8);
+//! # let cow: Cow<Pair<u8>> = Pair::butcher(Cow::Borrowed(&pair)).into();
+//! # assert_eq!(cow.1, 1);
+//! # #[derive(Butcher, Clone)]
+//! # enum Shape<T: Clone> { Circle(T), Empty }
+//! # let cow: Cow<Shape<u8>> = Shape::butcher(Cow::Owned(Shape::Circle(2))).into();
+//! # assert!(matches!(cow, Cow::Owned(Shape::Circle(2))));
+//! # let cow: Cow<Shape<u8>> = Shape::<u8>::butcher(Cow::Owned(Shape::Empty)).into();
+//! # assert!(matches!(cow, Cow::Owned(Shape::Empty)));
+//! ```
+//!
+//! When a field uses a [`ButcherTransform`] whose inverse can fail, the
+//! transform can override [`try_untransform`]. The butchered struct can then
+//! be converted back with [`Butcher::try_unbutcher`], or with the generated
+//! `TryFrom` implementation, which return an [`UnbutcherError`] instead of
+//! panicking:
+//!
+//! ```rust
+//! use butcher::methods::ButcherTransform;
+//! use butcher::{Butcher, UnbutcherError};
+//! use std::borrow::Cow;
+//! use std::convert::{TryFrom, TryInto};
+//! use std::num::NonZeroU32;
+//!
+//! struct Raw;
+//!
+//! impl<'cow> ButcherTransform<'cow, NonZeroU32> for Raw {
+//!     type Output = u32;
+//!
+//!     fn transform_owned(i: NonZeroU32) -> u32 {
+//!         i.get()
+//!     }
+//!
+//!     fn transform_borrowed(i: &'cow NonZeroU32) -> u32 {
+//!         i.get()
+//!     }
+//!
+//!     fn untransform(i: u32) -> NonZeroU32 {
+//!         NonZeroU32::new(i).expect("count must not be zero")
+//!     }
+//!
+//!     fn try_untransform(i: u32) -> Result<NonZeroU32, UnbutcherError> {
+//!         NonZeroU32::new(i).ok_or_else(|| UnbutcherError::new("count must not be zero"))
+//!     }
+//! }
+//!
+//! #[derive(Butcher, Clone, Debug)]
+//! struct Stock {
+//!     name: String,
+//!     #[butcher(transform = Raw)]
+//!     count: NonZeroU32,
+//! }
+//!
+//! let stock = Stock {
+//!     name: "apples".to_string(),
+//!     count: NonZeroU32::new(3).unwrap(),
+//! };
+//!
+//! let mut butchered = Stock::butcher(Cow::Borrowed(&stock));
+//! butchered.count -= 1;
+//! let stock: Stock = butchered.try_into().unwrap();
+//! assert_eq!(stock.count.get(), 2);
+//!
+//! let mut butchered = Stock::butcher(Cow::Owned(stock));
+//! butchered.count = 0;
+//! let error = Stock::try_from(butchered).unwrap_err();
+//! assert_eq!(error.message(), "count must not be zero");
+//! # // Enums, generics, and transforms nested in rebutchered fields must be
+//! # // supported as well.
+//! # #[derive(Butcher, Clone, Debug)]
+//! # enum Inventory<T: Clone> {
+//! #     Single(#[butcher(rebutcher)] Stock),
+//! #     Other(T),
+//! #     Empty,
+//! # }
+//! # let mut butchered = Inventory::<u8>::butcher(Cow::Owned(Inventory::Single(Stock {
+//! #     name: "pears".to_string(),
+//! #     count: NonZeroU32::new(1).unwrap(),
+//! # })));
+//! # assert!(Inventory::try_from(Inventory::<u8>::butcher(Cow::Owned(Inventory::Other(1)))).is_ok());
+//! # assert!(Inventory::try_from(Inventory::<u8>::butcher(Cow::Owned(Inventory::Empty))).is_ok());
+//! # if let ButcheredInventory::Single(stock) = &mut butchered {
+//! #     stock.count = 0;
+//! # }
+//! # assert!(Inventory::try_unbutcher(butchered).is_err());
+//! ```
+//!
+//! ## Listing the fields
+//!
+//! The butchered struct has a `FIELD_NAMES` associated constant, which lists
+//! the names of the fields in the order in which they are declared. The
+//! fields of tuple structs are named after their index:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Client {
+//!     name: String,
+//!     age: u8,
+//! }
+//!
+//! assert_eq!(ButcheredClient::FIELD_NAMES, &["name", "age"]);
+//! # // Tuple structs, generics and raw identifiers must be supported.
+//! # #[derive(Butcher, Clone)]
+//! # struct Pair<T>(T, u8);
+//! # assert_eq!(ButcheredPair::<u8>::FIELD_NAMES, &["0", "1"]);
+//! # #[derive(Butcher, Clone)]
+//! # struct Raw { r#type: u8 }
+//! # assert_eq!(ButcheredRaw::FIELD_NAMES, &["type"]);
+//! ```
+//!
+//! ## Reborrowing
+//!
+//! The butchered struct has a `reborrow` method, which borrows its content
+//! for a shorter lifetime. This allows to pass a butchered struct to a function
+//! without moving it.
+//!
+//! The fields using `regular`, `as_deref` or `unbox` are reborrowed, while the
+//! fields using `copy` or `into_owned` don't contain a [`Cow`], and are cloned.
+//! This method is not generated if a field uses `rebutcher` or `option_ref`.
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Client {
+//!     #[butcher(as_deref)]
+//!     name: String,
+//!     #[butcher(copy)]
+//!     age: u8,
+//! }
+//!
+//! fn greet<'a>(client: ButcheredClient<'a>) -> String {
+//!     format!("Hello {}, {} years old", client.name, client.age)
+//! }
+//!
+//! let client = Client {
+//!     name: "Grace Hopper".to_string(),
+//!     age: 85,
+//! };
+//!
+//! let butchered = Client::butcher(Cow::Owned(client));
+//!
+//! {
+//!     let reborrowed = butchered.reborrow();
+//!     assert!(matches!(reborrowed.name, Cow::Borrowed("Grace Hopper")));
+//!     assert_eq!(greet(reborrowed), "Hello Grace Hopper, 85 years old");
+//! }
+//!
+//! // The initial butchered struct can still be used.
+//! assert!(matches!(butchered.name, Cow::Owned(_)));
+//! ```
+//!
+//! ## Using a renamed crate
+//!
+//! The generated code refers to items of the `butcher` crate. If the crate is
+//! renamed in `Cargo.toml`, or re-exported by another crate, the path to use
+//! can be specified on the struct or enum:
+//!
+//! ```rust
+//! mod my_alias {
+//!     pub use ::butcher::*;
+//! }
+//!
+//! # // This shadows the crate name, so that the generated code can't rely on
+//! # // it.
+//! # mod butcher {}
+//! use my_alias::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! #[butcher(crate = my_alias)]
+//! struct Foo {
+//!     #[butcher(copy)]
+//!     bar: usize,
+//!     baz: String,
+//! }
+//!
+//! let foo = Foo { bar: 42, baz: String::from("Hello") };
+//!
+//! let ButcheredFoo { bar, baz } = Foo::butcher(Cow::Borrowed(&foo));
+//!
+//! assert_eq!(bar, 42);
+//! assert_eq!(baz, Cow::Borrowed("Hello"));
+//! ```
+//!
+//! ## Naming the butchered type
+//!
+//! The `alias` option generates a type alias for the butchered type. It
+//! takes the same generic parameters, and is declared with the same
+//! visibility:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! #[butcher(alias = "ClientParts")]
+//! struct Client {
+//!     name: String,
+//!     #[butcher(copy)]
+//!     age: u8,
+//! }
+//!
+//! fn describe(client: ClientParts) -> String {
+//!     format!("{} ({})", client.name, client.age)
+//! }
+//!
+//! let client = Client { name: String::from("Grace"), age: 85 };
+//! assert_eq!(describe(Client::butcher(Cow::Borrowed(&client))), "Grace (85)");
+//! # // The alias must forward the generics, and be available on enums.
+//! # #[derive(Butcher, Clone)]
+//! # #[butcher(alias = "PairParts")]
+//! # struct Pair<'a, T: Clone, const N: usize>(&'a str, [T; N]);
+//! # let _: fn(PairParts<'static, 'static, u8, 2>) -> ButcheredPair<'static, 'static, u8, 2> = |p| p;
+//! #
+//! # #[derive(Butcher, Clone)]
+//! # #[butcher(alias = "ShapeParts")]
+//! # enum Shape<T> { Circle(T), Square { side: T } }
+//! # let _: fn(ShapeParts<'static, u8>) -> ButcheredShape<'static, u8> = |s| s;
+//! ```
+//!
+//! ## Deriving inside macros
+//!
+//! The generated code only uses absolute paths, so that it does not depend on
+//! the items which are in scope. When `Butcher` is derived by a `macro_rules!`
+//! macro, the path to the crate can be specified with `$crate`, so that the
+//! macro can be used by crates which don't depend on `butcher`:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[doc(hidden)]
+//! pub mod __private {
+//!     pub use ::butcher::*;
+//! }
+//!
+//! macro_rules! point {
+//!     ($name:ident) => {
+//!         #[derive($crate::__private::Butcher, Clone)]
+//!         #[butcher(crate = $crate::__private)]
+//!         struct $name {
+//!             #[butcher(copy)]
+//!             x: i32,
+//!             #[butcher(copy)]
+//!             y: i32,
+//!         }
+//!     };
+//! }
+//!
+//! point!(Point);
+//!
+//! # // Local items which shadow the ones used by the generated code.
+//! # #[allow(dead_code)]
+//! # trait Clone {}
+//! # #[allow(dead_code)]
+//! # trait Default {}
+//! # #[allow(dead_code)]
+//! # trait ToOwned {}
+//! # #[derive(butcher::Butcher, std::clone::Clone)]
+//! # struct Shadowed<T> {
+//! #     #[butcher(as_deref)]
+//! #     a: String,
+//! #     #[butcher(skip)]
+//! #     b: Vec<T>,
+//! #     c: T,
+//! # }
+//! #
+//! fn main() {
+//!     let point = Point { x: 1, y: 2 };
+//!     let ButcheredPoint { x, y } = Point::butcher(Cow::Borrowed(&point));
+//!
+//!     assert_eq!((x, y), (1, 2));
+//! }
+//! ```
+//!
+//! ## Deprecated fields
+//!
+//! Fields and variants marked as `#[deprecated]` can be butchered. The code
+//! generated by the derive macro does not trigger deprecation warnings:
+//!
+//! ```rust
+//! #![deny(warnings)]
+//!
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Account {
+//!     name: String,
+//!     #[deprecated(note = "use `name` instead")]
+//!     login: String,
+//! }
+//!
+//! # #[derive(Butcher, Clone)]
+//! # enum Event {
+//! #     Login { #[deprecated] user: String },
+//! #     #[deprecated]
+//! #     Logout(String),
+//! # }
+//! #
+//! # fn main() {
+//! #[allow(deprecated)]
+//! let account = Account { name: "ada".to_string(), login: "ada".to_string() };
+//!
+//! let butchered = Account::butcher(Cow::Borrowed(&account));
+//! assert_eq!(*butchered.name, "ada");
+//! # let _ = butchered.to_owned_struct();
+//! # let _ = Event::unbutcher(ButcheredEvent::logout(Cow::Owned("ada".to_string())));
+//! # }
+//! ```
+//!
 //! ## Fixing triggered compilation errors
 //!
 //! While this proc macro generally generates code that compile on the first
@@ -245,18 +1433,110 @@
 //!     #[butcher(as_deref, T: Clone)]
 //!     elem: Vec<T>,
 //! }
+//! # // Bounds shared by multiple fields must be emitted once.
+//! # #[derive(Butcher, Clone)]
+//! # struct Pair<T> {
+//! #     #[butcher(as_deref, T: Clone)]
+//! #     a: Vec<T>,
+//! #     #[butcher(as_deref, T: Clone)]
+//! #     b: Vec<T>,
+//! # }
 //! ```
 //!
 //! If multiple trait bounds must be specified, then they have to be separated
 //! by commas, using regular trait bounds syntax (eg: with the syntax of
-//! [`WhereClauseItem`] defined in the reference).
+//! [`WhereClauseItem`] defined in the reference). A bound which is specified on
+//! multiple fields is emitted only once in the generated code.
+//!
+//! The bounds written on the struct itself, either in its generics declaration
+//! or in its where clause, are taken into account as well. Default values of
+//! generic types are kept in the butchered struct:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Cache<T = String>
+//! where
+//!     T: Clone,
+//! {
+//!     #[butcher(as_deref)]
+//!     items: Vec<T>,
+//! }
+//!
+//! let cache: Cache = Cache { items: vec![String::from("foo")] };
+//!
+//! let butchered: ButcheredCache = Cache::butcher(Cow::Borrowed(&cache));
+//! assert!(matches!(butchered.items, Cow::Borrowed([_])));
+//!
+//! let cache: Cache<String> = Cache::unbutcher(butchered);
+//! assert_eq!(cache.items, ["foo"]);
+//! # #[derive(Butcher, Clone)]
+//! # struct Inline<'a, 'b: 'a, K: Ord + Clone, V = u32>
+//! # where
+//! #     V: std::fmt::Debug,
+//! # {
+//! #     #[butcher(copy)]
+//! #     k: &'a K,
+//! #     v: V,
+//! #     s: &'b str,
+//! # }
+//! ```
+//!
+//! The butchered struct has an additional lifetime, which is usually named
+//! `'cow`. If the struct already declares a lifetime with this name, then
+//! another name is picked:
+//!
+//! ```rust
+//! use butcher::Butcher;
+//! use std::borrow::Cow;
+//!
+//! #[derive(Butcher, Clone)]
+//! struct Borrowing<'cow> {
+//!     #[butcher(copy)]
+//!     name: &'cow str,
+//! }
+//! # // The lifetime introduced by `reborrow` must be fresh as well.
+//! # #[derive(Butcher, Clone)]
+//! # struct Reborrowing<'cow, 'reborrow>(#[butcher(copy)] &'cow str, &'reborrow u8);
+//! # #[derive(Butcher, Clone)]
+//! # enum BorrowingEnum<'cow, 'cow_> {
+//! #     Name(&'cow str),
+//! #     Other(#[butcher(copy)] &'cow_ str),
+//! # }
+//!
+//! fn name<'cow>(b: &Borrowing<'cow>) -> &'cow str {
+//!     Borrowing::butcher(Cow::Borrowed(b)).name
+//! }
+//! ```
 //!
 //! [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
 //! [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+//! [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
 //! [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
+//! [std-copy]: https://doc.rust-lang.org/std/marker/trait.Copy.html
+//! [std-clone]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+//! [std-to-owned]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
 //! [`WhereClauseItem`]: https://doc.rust-lang.org/reference/items/generics.html#where-clauses
 //! [`AsDeref`]: ../methods/struct.AsDeref.html
+//! [`Chain`]: ../methods/struct.Chain.html
 //! [`Copy`]: ../methods/struct.Copy.html
+//! [`FlattenVec`]: ../methods/struct.FlattenVec.html
+//! [`IntoOwned`]: ../methods/struct.IntoOwned.html
+//! [`OptionRef`]: ../methods/struct.OptionRef.html
+//! [`MaybeOwned`]: ../maybe_owned/enum.MaybeOwned.html
+//! [`MaybeOwnedMethod`]: ../methods/struct.MaybeOwnedMethod.html
+//! [`IntoVec`]: ../into_vec/trait.IntoVec.html
+//! [`try_untransform`]: ../methods/trait.ButcherTransform.html#method.try_untransform
+//! [`Butcher::try_unbutcher`]: ../trait.Butcher.html#method.try_unbutcher
+//! [`UnbutcherError`]: ../struct.UnbutcherError.html
+//! [`Len`]: ../len/trait.Len.html
 //! [`Rebutcher`]: ../methods/struct.Rebutcher.html
 //! [`Regular`]: ../methods/struct.Regular.html
+//! [`Skip`]: ../methods/struct.Skip.html
+//! [`SkipDefault`]: ../methods/trait.SkipDefault.html
+//! [`Weak`]: ../methods/struct.Weak.html
+//! [`ButcherTransform`]: ../methods/trait.ButcherTransform.html
+//! [`Snapshot`]: ../methods/struct.Snapshot.html
 //! [`Unbox`]: ../methods/struct.Unbox.html
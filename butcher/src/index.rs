@@ -0,0 +1,187 @@
+//! Allows to get elements of a slice wrapped in a `Cow`.
+//!
+//! See the documentation for [`CowIndex`] for more.
+//!
+//! [`CowIndex`]: trait.CowIndex.html
+
+use std::borrow::Cow;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Allows to get an element or a subslice of a slice wrapped in a `Cow`.
+///
+/// The returned value is borrowed if the initial data is borrowed. Otherwise,
+/// the requested elements are moved out of the initial data, without any
+/// clone.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use butcher::index::CowIndex;
+///
+/// let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4]);
+///
+/// assert_eq!(numbers.clone().get_cow(1), Some(Cow::Borrowed(&2)));
+/// assert_eq!(numbers.clone().get_cow(1..3), Some(Cow::Borrowed(&[2_u32, 3] as &[_])));
+/// assert_eq!(numbers.get_cow(42), None);
+/// ```
+pub trait CowIndex<'a, T: Clone> {
+    /// Returns an element or a subslice, depending on the type of index, or
+    /// `None` if the index is out of bounds.
+    fn get_cow<Idx>(self, index: Idx) -> Option<Cow<'a, Idx::Output>>
+    where
+        Idx: CowSliceIndex<T>;
+}
+
+impl<'a, T> CowIndex<'a, T> for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    fn get_cow<Idx>(self, index: Idx) -> Option<Cow<'a, Idx::Output>>
+    where
+        Idx: CowSliceIndex<T>,
+    {
+        match self {
+            Cow::Borrowed(slice) => index.get_borrowed(slice).map(Cow::Borrowed),
+            Cow::Owned(vec) => index.get_owned(vec).map(Cow::Owned),
+        }
+    }
+}
+
+impl<'a, T> CowIndex<'a, T> for Cow<'a, Vec<T>>
+where
+    T: Clone,
+{
+    fn get_cow<Idx>(self, index: Idx) -> Option<Cow<'a, Idx::Output>>
+    where
+        Idx: CowSliceIndex<T>,
+    {
+        match self {
+            Cow::Borrowed(vec) => index.get_borrowed(vec.as_slice()).map(Cow::Borrowed),
+            Cow::Owned(vec) => index.get_owned(vec).map(Cow::Owned),
+        }
+    }
+}
+
+/// A type which can be used to index a slice wrapped in a `Cow`.
+///
+/// This trait is implemented for `usize`, which returns a single element, and
+/// for every range of `usize`, which return a subslice.
+pub trait CowSliceIndex<T> {
+    /// The type returned when the slice is indexed.
+    type Output: ToOwned + ?Sized;
+
+    /// Returns the output from a borrowed slice.
+    fn get_borrowed(self, slice: &[T]) -> Option<&Self::Output>;
+
+    /// Returns the output from an owned vector, moving the elements out of it.
+    fn get_owned(self, vec: Vec<T>) -> Option<<Self::Output as ToOwned>::Owned>;
+}
+
+impl<T> CowSliceIndex<T> for usize
+where
+    T: Clone,
+{
+    type Output = T;
+
+    fn get_borrowed(self, slice: &[T]) -> Option<&T> {
+        slice.get(self)
+    }
+
+    fn get_owned(self, mut vec: Vec<T>) -> Option<T> {
+        if self < vec.len() {
+            Some(vec.swap_remove(self))
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_cow_slice_index_for_ranges {
+    ( $( $range:ty ),* $(,)? ) => {
+        $(
+            impl<T> CowSliceIndex<T> for $range
+            where
+                T: Clone,
+            {
+                type Output = [T];
+
+                fn get_borrowed(self, slice: &[T]) -> Option<&[T]> {
+                    slice.get(self)
+                }
+
+                fn get_owned(self, mut vec: Vec<T>) -> Option<Vec<T>> {
+                    // Ensures that the range is in bounds before moving anything.
+                    vec.get(self.clone())?;
+
+                    Some(vec.drain(self).collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_cow_slice_index_for_ranges! {
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+}
+
+#[cfg(test)]
+#[allow(clippy::owned_cow)]
+mod cow_index {
+    use super::*;
+
+    #[test]
+    fn single_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+
+        assert!(matches!(
+            numbers.clone().get_cow(0),
+            Some(Cow::Borrowed(&1))
+        ));
+        assert!(matches!(
+            numbers.clone().get_cow(2),
+            Some(Cow::Borrowed(&3))
+        ));
+        assert_eq!(numbers.get_cow(3), None);
+    }
+
+    #[test]
+    fn single_owned() {
+        let numbers: Cow<Vec<u32>> = Cow::Owned(vec![1, 2, 3]);
+
+        assert!(matches!(numbers.clone().get_cow(1), Some(Cow::Owned(2))));
+        assert_eq!(numbers.get_cow(3), None);
+    }
+
+    #[test]
+    fn range_borrowed() {
+        let tmp: Vec<u32> = vec![1, 2, 3, 4];
+        let numbers: Cow<Vec<u32>> = Cow::Borrowed(&tmp);
+
+        let subslice = numbers.clone().get_cow(1..=2);
+        assert!(matches!(subslice, Some(Cow::Borrowed(&[2, 3]))));
+
+        assert_eq!(
+            numbers.clone().get_cow(..),
+            Some(Cow::Borrowed(&[1_u32, 2, 3, 4] as &[_]))
+        );
+        assert_eq!(numbers.get_cow(3..5), None);
+    }
+
+    #[test]
+    fn range_owned() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4]);
+
+        let subslice = numbers.clone().get_cow(2..);
+        assert_eq!(subslice, Some(Cow::Owned(vec![3, 4])));
+        assert!(matches!(subslice, Some(Cow::Owned(_))));
+
+        assert_eq!(numbers.clone().get_cow(..=4), None);
+        assert_eq!(numbers.get_cow(5..), None);
+    }
+}
@@ -0,0 +1,73 @@
+//! Allows to get a `Vec` out of a collection wrapped in a `Cow`, cloning the
+//! elements only when they are borrowed.
+//!
+//! See the documentation for [`IntoVec`] for more.
+//!
+//! [`IntoVec`]: trait.IntoVec.html
+
+use std::borrow::Cow;
+
+/// A collection which can be converted into a `Vec` of its elements.
+///
+/// This trait is used by the derive macro: single-field tuple structs whose
+/// butchered field implements `IntoVec` get an `into_inner_vec` method. The
+/// elements are moved out of owned collections, and cloned only when the
+/// collection is borrowed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use butcher::into_vec::IntoVec;
+///
+/// let borrowed: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+/// let owned: Cow<Vec<u32>> = Cow::Owned(vec![4, 5]);
+///
+/// assert_eq!(borrowed.into_vec(), vec![1, 2, 3]);
+/// assert_eq!(owned.into_vec(), vec![4, 5]);
+/// ```
+pub trait IntoVec {
+    /// The type of the elements.
+    type Item;
+
+    /// Converts the collection into a `Vec`.
+    fn into_vec(self) -> Vec<Self::Item>;
+}
+
+impl<T> IntoVec for Vec<T> {
+    type Item = T;
+
+    fn into_vec(self) -> Vec<T> {
+        self
+    }
+}
+
+impl<T> IntoVec for Box<[T]> {
+    type Item = T;
+
+    fn into_vec(self) -> Vec<T> {
+        <[T]>::into_vec(self)
+    }
+}
+
+impl<'a, T> IntoVec for Cow<'a, Vec<T>>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn into_vec(self) -> Vec<T> {
+        self.into_owned()
+    }
+}
+
+impl<'a, T> IntoVec for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn into_vec(self) -> Vec<T> {
+        self.into_owned()
+    }
+}
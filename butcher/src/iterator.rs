@@ -4,8 +4,23 @@
 //!
 //! [`CowIter`]: enum.CowIter.html
 
-use std::borrow::Cow;
+use std::borrow::{Borrow, Cow};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{
+    btree_map, hash_map, hash_map::RandomState, BTreeMap, HashMap, HashSet, VecDeque,
+};
+use std::convert::TryInto;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::iter::{Fuse, FusedIterator, Skip, Sum, Take};
 use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+use std::slice;
+use std::vec;
+
+use crate::len::Len;
 
 /// An iterator over data wrapped in `Cow`.
 ///
@@ -48,6 +63,60 @@ use std::marker::PhantomData;
 /// assert_eq!(input.next(), None)
 /// ```
 ///
+/// Fixed-size arrays can be iterated as well, without converting them to a
+/// slice first:
+///
+/// ```
+/// use std::borrow::Cow;
+/// use butcher::iterator::IntoCowIterator;
+///
+/// let data: Cow<[u32; 3]> = Cow::Owned([1, 2, 3]);
+/// let elements = data.into_cow_iter().collect::<Vec<_>>();
+///
+/// assert_eq!(elements, [Cow::Owned(1), Cow::Owned(2), Cow::Owned(3)]);
+/// ```
+///
+/// The elements can be yielded from the back as well, when the underlying
+/// iterators are double-ended:
+///
+/// ```
+/// use std::borrow::Cow;
+/// use butcher::iterator::IntoCowIterator;
+///
+/// let data: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+/// let elements = data.into_cow_iter().rev().collect::<Vec<_>>();
+///
+/// assert_eq!(elements, [Cow::Borrowed(&3), Cow::Borrowed(&2), Cow::Borrowed(&1)]);
+/// ```
+///
+/// The number of remaining elements is known when the underlying iterators
+/// implement [`ExactSizeIterator`], which allows `collect` to preallocate:
+///
+/// ```
+/// use std::borrow::Cow;
+/// use butcher::iterator::IntoCowIterator;
+///
+/// let data: Cow<Vec<u32>> = Cow::Owned(vec![1, 2, 3]);
+/// let mut elements = data.into_cow_iter();
+/// elements.next();
+///
+/// assert_eq!(elements.len(), 2);
+/// assert_eq!(elements.size_hint(), (2, Some(2)));
+/// ```
+///
+/// An `Option` yields its value if it is `Some`, and nothing otherwise. This
+/// allows to flatten optional fields of a butchered struct:
+///
+/// ```
+/// use std::borrow::Cow;
+/// use butcher::iterator::IntoCowIterator;
+///
+/// let data: Cow<Option<String>> = Cow::Owned(Some("hello".to_string()));
+/// let elements = data.into_cow_iter().collect::<Vec<_>>();
+///
+/// assert_eq!(elements, [Cow::<String>::Owned("hello".to_string())]);
+/// ```
+///
 /// # Generics
 ///
 /// This enum uses a lot of generics in order to work. Users should not care
@@ -59,6 +128,8 @@ use std::marker::PhantomData;
 /// circumvented by specifying the generic type of the associated `Cow`
 /// somewhere. This can be specified when the `Cow` is created, or in the
 /// function signature.
+///
+/// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
 pub enum CowIter<'a, I, Input, Iterr1, Iterr2>
 where
     I: 'a + ToOwned,
@@ -84,7 +155,7 @@ where
     /// Creates a `CowIter` from a `Cow` containing an owned or borrowed object
     /// which can be iterated over.
     // TODO: write a test for this function
-    pub fn from_cow(i: Cow<'a, Input>) -> CowIter<I, Input, Iterr1, Iterr2> {
+    pub fn from_cow(i: Cow<'a, Input>) -> CowIter<'a, I, Input, Iterr1, Iterr2> {
         match i {
             Cow::Owned(i) => CowIter::Owned(i.into_iter(), PhantomData),
             Cow::Borrowed(i) => {
@@ -93,102 +164,3059 @@ where
             }
         }
     }
+
+    /// Reduces the elements to a single one, by repeatedly applying a
+    /// reducing operation.
+    ///
+    /// This behaves like [`Iterator::reduce`], except that the accumulator is
+    /// a `Cow`. As such, the reducer can keep borrowing data when it returns a
+    /// borrowed element.
+    ///
+    /// [`Iterator::reduce`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.reduce
+    pub fn reduce_cow<F>(mut self, f: F) -> Option<Cow<'a, I>>
+    where
+        F: FnMut(Cow<'a, I>, Cow<'a, I>) -> Cow<'a, I>,
+    {
+        let first = self.next()?;
+        Some(self.fold(first, f))
+    }
+
+    /// Returns the maximum element, comparing the elements without converting
+    /// them to their owned form.
+    ///
+    /// If several elements are equally maximum, the last element is returned,
+    /// just like [`Iterator::max`].
+    ///
+    /// [`Iterator::max`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.max
+    pub fn max_cow(self) -> Option<Cow<'a, I>>
+    where
+        I: Ord,
+    {
+        self.reduce_cow(|max, element| if *element >= *max { element } else { max })
+    }
+
+    /// Returns the minimum element, comparing the elements without converting
+    /// them to their owned form.
+    ///
+    /// If several elements are equally minimum, the first element is returned,
+    /// just like [`Iterator::min`].
+    ///
+    /// [`Iterator::min`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.min
+    pub fn min_cow(self) -> Option<Cow<'a, I>>
+    where
+        I: Ord,
+    {
+        self.reduce_cow(|min, element| if *element < *min { element } else { min })
+    }
+
+    /// Returns both the minimum and the maximum element, in a single pass.
+    ///
+    /// The elements are compared without converting them to their owned form.
+    /// Ties are resolved like in [`min_cow`] and [`max_cow`]. The first element
+    /// is cloned, which is cheap when it is borrowed, as it is the initial
+    /// minimum and maximum. If there is a single element, both are equal.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[3, 1, 4]);
+    /// let (min, max) = CowIter::from_cow(data).min_max_cow().unwrap();
+    ///
+    /// assert_eq!((*min, *max), (1, 4));
+    /// ```
+    ///
+    /// [`min_cow`]: enum.CowIter.html#method.min_cow
+    /// [`max_cow`]: enum.CowIter.html#method.max_cow
+    pub fn min_max_cow(mut self) -> Option<(Cow<'a, I>, Cow<'a, I>)>
+    where
+        I: Ord,
+    {
+        let first = self.next()?;
+
+        let min_max = self.fold((first.clone(), first), |(min, max), element| {
+            if *element < *min {
+                (element, max)
+            } else if *element >= *max {
+                (min, element)
+            } else {
+                (min, max)
+            }
+        });
+
+        Some(min_max)
+    }
+
+    /// Concatenates the elements into a single `String`, placing `sep`
+    /// between each of them.
+    ///
+    /// The elements are read through `AsRef<str>`, so none of them is cloned,
+    /// whether the initial data is borrowed or owned.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[String]> = Cow::Owned(vec!["a".to_string(), "b".to_string()]);
+    ///
+    /// assert_eq!(CowIter::from_cow(data).join_cow("-"), "a-b");
+    /// ```
+    pub fn join_cow(self, sep: &str) -> String
+    where
+        I: AsRef<str>,
+    {
+        let mut joined = String::new();
+
+        for (idx, element) in self.enumerate() {
+            if idx != 0 {
+                joined.push_str(sep);
+            }
+
+            joined.push_str(AsRef::<str>::as_ref(&*element));
+        }
+
+        joined
+    }
+
+    /// Searches for an element satisfying a predicate.
+    ///
+    /// Unlike [`Iterator::find`], the predicate takes a reference to the
+    /// element itself rather than to the `Cow` wrapping it. The matching
+    /// element is returned with its state. The iterator can be used again to
+    /// search for the next matching element.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[1, 4, 9, 16]);
+    /// let mut iter = CowIter::from_cow(data);
+    ///
+    /// assert_eq!(iter.find_cow(|n| n % 2 == 0), Some(Cow::Borrowed(&4)));
+    /// assert_eq!(iter.find_cow(|n| n % 2 == 0), Some(Cow::Borrowed(&16)));
+    /// assert_eq!(iter.find_cow(|n| n % 2 == 0), None);
+    /// ```
+    ///
+    /// [`Iterator::find`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.find
+    pub fn find_cow<F>(&mut self, mut pred: F) -> Option<Cow<'a, I>>
+    where
+        F: FnMut(&I) -> bool,
+    {
+        self.find(|element| pred(element))
+    }
+
+    /// Returns the index of the maximum element, comparing the elements
+    /// without converting them to their owned form.
+    ///
+    /// If several elements are equally maximum, the index of the last element
+    /// is returned, just like [`max_cow`].
+    ///
+    /// [`max_cow`]: enum.CowIter.html#method.max_cow
+    pub fn position_max_cow(self) -> Option<usize>
+    where
+        I: Ord,
+    {
+        self.enumerate()
+            .fold(
+                None,
+                |max: Option<(usize, Cow<'a, I>)>, (index, element)| match max {
+                    Some((_, ref max_element)) if *element < **max_element => max,
+                    _ => Some((index, element)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the minimum element, comparing the elements
+    /// without converting them to their owned form.
+    ///
+    /// If several elements are equally minimum, the index of the first element
+    /// is returned, just like [`min_cow`].
+    ///
+    /// [`min_cow`]: enum.CowIter.html#method.min_cow
+    pub fn position_min_cow(self) -> Option<usize>
+    where
+        I: Ord,
+    {
+        self.enumerate()
+            .fold(
+                None,
+                |min: Option<(usize, Cow<'a, I>)>, (index, element)| match min {
+                    Some((_, ref min_element)) if *element >= **min_element => min,
+                    _ => Some((index, element)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
+
+    /// Checks if the elements are sorted, comparing them without converting
+    /// them to their owned form.
+    ///
+    /// See [`is_sorted_by_cow`] for more information.
+    ///
+    /// [`is_sorted_by_cow`]: enum.CowIter.html#method.is_sorted_by_cow
+    pub fn is_sorted_cow(self) -> bool
+    where
+        I: PartialOrd,
+    {
+        self.is_sorted_by_cow(PartialOrd::partial_cmp)
+    }
+
+    /// Checks if the elements are sorted using the given comparator function.
+    ///
+    /// The elements are sorted if `compare` returns `Some(Ordering::Less)` or
+    /// `Some(Ordering::Equal)` for every pair of consecutive elements. Returning
+    /// `None` means that the elements are not sorted.
+    pub fn is_sorted_by_cow<F>(mut self, mut compare: F) -> bool
+    where
+        F: FnMut(&I, &I) -> Option<Ordering>,
+    {
+        let mut last = match self.next() {
+            Some(element) => element,
+            None => return true,
+        };
+
+        for current in self {
+            match compare(&last, &current) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => {}
+                Some(Ordering::Greater) | None => return false,
+            }
+
+            last = current;
+        }
+
+        true
+    }
+
+    /// Sums the values returned by `f` for each element.
+    ///
+    /// `f` takes a reference to each element, so that no element is cloned.
+    pub fn sum_by<B, F>(self, mut f: F) -> B
+    where
+        B: Sum,
+        F: FnMut(&I) -> B,
+    {
+        self.map(|element| f(&element)).sum()
+    }
+
+    /// Sums the lengths of the elements, such as the number of bytes of
+    /// strings or the number of elements of vectors.
+    ///
+    /// The lengths are read from references to the elements, so that no
+    /// element is cloned. See [`Len`] for the types which have a length.
+    ///
+    /// [`Len`]: ../len/trait.Len.html
+    pub fn total_len(self) -> usize
+    where
+        I: Len,
+    {
+        self.sum_by(Len::len)
+    }
+
+    /// Calls `f` on each element, stopping at the first error.
+    ///
+    /// `f` takes a reference to each element, which is not wrapped in a `Cow`
+    /// beforehand. The first error returned by `f` is returned.
+    pub fn try_for_each_cow<E, F>(self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&I) -> Result<(), E>,
+    {
+        match self {
+            CowIter::Borrowed(mut it, _) => it.try_for_each(f),
+            CowIter::Owned(mut it, _) => it.try_for_each(|element| f(element.borrow())),
+        }
+    }
+
+    /// Lexicographically compares the elements with the ones of `other`.
+    ///
+    /// The elements are compared by reference, so none of them is cloned.
+    pub fn cmp_cow<J>(self, other: J) -> Ordering
+    where
+        I: Ord,
+        J: IntoCowIterator<Item = I>,
+        <J::IntoIter as Iterator>::Item: Borrow<I>,
+    {
+        let mut other = other.into_cow_iter();
+
+        for element in self {
+            let other_element = match other.next() {
+                Some(other_element) => other_element,
+                None => return Ordering::Greater,
+            };
+
+            match element.as_ref().cmp(other_element.borrow()) {
+                Ordering::Equal => {}
+                non_equal => return non_equal,
+            }
+        }
+
+        match other.next() {
+            Some(_) => Ordering::Less,
+            None => Ordering::Equal,
+        }
+    }
+
+    /// Creates an iterator which maps each element until the mapping function
+    /// returns `None`.
+    ///
+    /// This behaves like [`Iterator::map_while`]. Once the mapping function
+    /// returns `None`, no more elements are yielded.
+    ///
+    /// [`Iterator::map_while`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.map_while
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn map_while_cow<F, B>(self, f: F) -> MapWhileCow<Self, F>
+    where
+        F: FnMut(Cow<'a, I>) -> Option<B>,
+    {
+        MapWhileCow {
+            iter: self,
+            f,
+            done: false,
+        }
+    }
+
+    /// Creates an iterator which calls `f` with the remaining elements to
+    /// produce each of its values.
+    ///
+    /// The closure can take as many elements as it needs from the iterator.
+    /// The iteration stops once it returns `None`.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+    /// let mut pairs = CowIter::from_cow(data).batching_cow(|it| match it.next() {
+    ///     Some(first) => it.next().map(|second| (first, second)),
+    ///     None => None,
+    /// });
+    ///
+    /// assert_eq!(pairs.next(), Some((Cow::Borrowed(&1), Cow::Borrowed(&2))));
+    /// assert_eq!(pairs.next(), Some((Cow::Borrowed(&3), Cow::Borrowed(&4))));
+    /// assert_eq!(pairs.next(), None);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn batching_cow<B, F>(self, f: F) -> BatchingCow<Self, F>
+    where
+        F: FnMut(&mut Self) -> Option<B>,
+    {
+        BatchingCow { iter: self, f }
+    }
+
+    /// Groups the elements by the key returned by `key`.
+    ///
+    /// The elements don't need to be adjacent to be grouped together. Each
+    /// element keeps its initial order in its group, and stays borrowed if it
+    /// was borrowed.
+    pub fn group_into_map<K, F>(self, mut key: F) -> HashMap<K, Vec<Cow<'a, I>>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&I) -> K,
+    {
+        let mut groups = HashMap::new();
+
+        for element in self {
+            groups
+                .entry(key(&element))
+                .or_insert_with(Vec::new)
+                .push(element);
+        }
+
+        groups
+    }
+
+    /// Creates an iterator which iterates from back to front, mapping each
+    /// element with `f`.
+    ///
+    /// The returned iterator implements [`ExactSizeIterator`] when the
+    /// `CowIter` implements it.
+    ///
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn rev_map_cow<F, B>(self, f: F) -> RevMapCow<Self, F>
+    where
+        Self: DoubleEndedIterator,
+        F: FnMut(Cow<'a, I>) -> B,
+    {
+        RevMapCow { iter: self, f }
+    }
+
+    /// Collects the elements in reverse order.
+    ///
+    /// The returned `Cow` is always owned: the reversed elements can't be
+    /// borrowed from the initial data, so each borrowed element is cloned.
+    pub fn rev_collect_cow(self) -> Cow<'a, Vec<I::Owned>>
+    where
+        I::Owned: Clone + 'a,
+    {
+        let mut elements = self.map(Cow::into_owned).collect::<Vec<_>>();
+        elements.reverse();
+
+        Cow::Owned(elements)
+    }
+
+    /// Collects the elements, rotated to the left by `k` positions.
+    ///
+    /// The returned `Cow` is always owned: the rotated elements can't be
+    /// borrowed from the initial data, so each borrowed element is cloned.
+    /// Owned elements are moved. `k` may be greater than the number of
+    /// elements.
+    pub fn rotate_left_cow(self, k: usize) -> Cow<'a, Vec<I::Owned>>
+    where
+        I::Owned: Clone + 'a,
+    {
+        let mut elements = self.map(Cow::into_owned).collect::<Vec<_>>();
+
+        if !elements.is_empty() {
+            let k = k % elements.len();
+            elements.rotate_left(k);
+        }
+
+        Cow::Owned(elements)
+    }
+
+    /// Returns the elements sorted in ascending order.
+    ///
+    /// The elements are compared by reference and are never cloned, so each
+    /// element stays borrowed if it was borrowed. The sort is stable.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[3, 1, 2]);
+    /// let sorted = CowIter::from_cow(data).sorted_cow();
+    ///
+    /// assert_eq!(sorted, [Cow::Borrowed(&1), Cow::Borrowed(&2), Cow::Borrowed(&3)]);
+    /// ```
+    pub fn sorted_cow(self) -> Vec<Cow<'a, I>>
+    where
+        I: Ord,
+    {
+        let mut elements = self.collect::<Vec<_>>();
+        elements.sort();
+        elements
+    }
+
+    /// Removes the duplicated elements, keeping the first occurrence of each
+    /// element.
+    ///
+    /// The elements don't need to be adjacent to be considered duplicates.
+    /// They are hashed and compared by reference, so each kept element stays
+    /// borrowed if it was borrowed.
+    pub fn unique_cow(self) -> Vec<Cow<'a, I>>
+    where
+        I: Eq + Hash,
+    {
+        let state = RandomState::new();
+        let mut indices_by_hash = HashMap::<u64, Vec<usize>>::new();
+        let mut elements = Vec::<Cow<'a, I>>::new();
+
+        for element in self {
+            let mut hasher = state.build_hasher();
+            Hash::hash(&*element, &mut hasher);
+
+            let indices = indices_by_hash.entry(hasher.finish()).or_default();
+            let is_duplicate = indices.iter().any(|&index| *elements[index] == *element);
+
+            if !is_duplicate {
+                indices.push(elements.len());
+                elements.push(element);
+            }
+        }
+
+        elements
+    }
+
+    /// Returns the elements which are also yielded by `other`.
+    ///
+    /// The elements are compared by reference. The retained elements keep the
+    /// state of the elements of `self`: they stay borrowed if they were
+    /// borrowed. Duplicates are retained as well.
+    pub fn intersect_cow<J>(self, other: J) -> Vec<Cow<'a, I>>
+    where
+        I: Eq + Hash,
+        J: IntoCowIterator<Item = I>,
+        <J::IntoIter as Iterator>::Item: Borrow<I>,
+    {
+        self.filter_by_membership(other, true)
+    }
+
+    /// Returns the elements which are not yielded by `other`.
+    ///
+    /// The elements are compared by reference. The retained elements keep the
+    /// state of the elements of `self`: they stay borrowed if they were
+    /// borrowed. Duplicates are retained as well.
+    pub fn difference_cow<J>(self, other: J) -> Vec<Cow<'a, I>>
+    where
+        I: Eq + Hash,
+        J: IntoCowIterator<Item = I>,
+        <J::IntoIter as Iterator>::Item: Borrow<I>,
+    {
+        self.filter_by_membership(other, false)
+    }
+
+    fn filter_by_membership<J>(self, other: J, keep_members: bool) -> Vec<Cow<'a, I>>
+    where
+        I: Eq + Hash,
+        J: IntoCowIterator<Item = I>,
+        <J::IntoIter as Iterator>::Item: Borrow<I>,
+    {
+        let other = other.into_cow_iter().collect::<Vec<_>>();
+        let members = other.iter().map(Borrow::borrow).collect::<HashSet<&I>>();
+
+        self.filter(|element| members.contains(element.as_ref()) == keep_members)
+            .collect()
+    }
+
+    /// Extends a collection with the owned form of the elements.
+    ///
+    /// Owned elements are moved into `target`, while borrowed elements are
+    /// cloned.
+    pub fn extend_into<E>(self, target: &mut E)
+    where
+        E: Extend<I::Owned>,
+    {
+        target.extend(self.map(Cow::into_owned));
+    }
+
+    /// Returns the first element and an iterator over the remaining ones, or
+    /// `None` if there is no element.
+    ///
+    /// Borrowed elements stay borrowed, so nothing is cloned.
+    pub fn split_first_cow(mut self) -> Option<(Cow<'a, I>, Self)> {
+        let first = self.next()?;
+        Some((first, self))
+    }
+
+    /// Returns the last element and an iterator over the remaining ones, or
+    /// `None` if there is no element.
+    ///
+    /// Borrowed elements stay borrowed, so nothing is cloned.
+    pub fn split_last_cow(mut self) -> Option<(Cow<'a, I>, Self)>
+    where
+        Iterr1: DoubleEndedIterator,
+        Iterr2: DoubleEndedIterator,
+    {
+        let last = self.next_back()?;
+        Some((last, self))
+    }
+
+    /// Creates an iterator which yields the `n` first elements.
+    ///
+    /// The returned iterator implements [`ExactSizeIterator`] and
+    /// [`DoubleEndedIterator`] when the `CowIter` implements them.
+    ///
+    /// As with the other adapters, the returned iterator must be used:
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(unused_must_use)]
+    ///
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+    /// CowIter::from_cow(data).take_cow(2);
+    /// ```
+    ///
+    /// The same code compiles once the iterator is used, so the error above
+    /// can only come from the `unused_must_use` lint:
+    ///
+    /// ```rust
+    /// #![deny(unused_must_use)]
+    ///
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+    /// let _taken = CowIter::from_cow(data).take_cow(2);
+    /// ```
+    ///
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
+    /// [`DoubleEndedIterator`]: https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn take_cow(self, n: usize) -> TakeCow<Self> {
+        TakeCow { iter: self.take(n) }
+    }
+
+    /// Creates an iterator which skips the `n` first elements.
+    ///
+    /// The returned iterator implements [`ExactSizeIterator`] and
+    /// [`DoubleEndedIterator`] when the `CowIter` implements them.
+    ///
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
+    /// [`DoubleEndedIterator`]: https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn skip_cow(self, n: usize) -> SkipCow<Self> {
+        SkipCow { iter: self.skip(n) }
+    }
+
+    /// Creates an iterator which yields the elements along with their index,
+    /// counting from `start` instead of zero.
+    ///
+    /// This is useful when the data is processed by chunks, and the indices
+    /// must be relative to the whole data. The returned iterator implements
+    /// [`ExactSizeIterator`] when the `CowIter` implements it.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let chunk: Cow<[_]> = Cow::Borrowed(&['c', 'd']);
+    /// let mut iter = CowIter::from_cow(chunk).enumerate_from_cow(2);
+    ///
+    /// assert_eq!(iter.next(), Some((2, Cow::Borrowed(&'c'))));
+    /// assert_eq!(iter.next(), Some((3, Cow::Borrowed(&'d'))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`ExactSizeIterator`]: https://doc.rust-lang.org/std/iter/trait.ExactSizeIterator.html
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn enumerate_from_cow(self, start: usize) -> EnumerateFromCow<Self> {
+        EnumerateFromCow {
+            iter: self,
+            count: start,
+        }
+    }
+
+    /// Creates an iterator which yields the elements of `self` and of `other`
+    /// alternately, starting with `self`.
+    ///
+    /// Once one of them is exhausted, the remaining elements of the other one
+    /// are yielded. Each element stays borrowed if it was borrowed in its
+    /// source.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let odd: Cow<[_]> = Cow::Borrowed(&[1, 3, 5]);
+    /// let even: Cow<[_]> = Cow::Owned(vec![2, 4]);
+    ///
+    /// let interleaved: Vec<_> = CowIter::from_cow(odd)
+    ///     .interleave_cow(even)
+    ///     .map(Cow::into_owned)
+    ///     .collect();
+    /// assert_eq!(interleaved, [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn interleave_cow<J>(self, other: J) -> InterleaveCow<Self, J::IntoIter>
+    where
+        J: IntoCowIterator<Item = I>,
+        J::IntoIter: Iterator<Item = Cow<'a, I>>,
+    {
+        InterleaveCow {
+            first: self.fuse(),
+            second: other.into_cow_iter().fuse(),
+            second_next: false,
+        }
+    }
+
+    /// Creates an iterator which always returns `None` once it is exhausted,
+    /// and records whether any of the yielded elements was owned.
+    ///
+    /// This helps to decide whether the initial data can be kept borrowed.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[1, 2, 3]);
+    /// let mut iter = CowIter::from_cow(data).fuse_cow();
+    ///
+    /// assert_eq!(iter.by_ref().count(), 3);
+    /// assert!(!iter.any_owned());
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn fuse_cow(self) -> FuseCow<Self> {
+        FuseCow {
+            iter: self.fuse(),
+            any_owned: false,
+        }
+    }
+
+    /// Creates an iterator which yields the elements in arrays of `N`
+    /// elements.
+    ///
+    /// The last elements are dropped if there are not enough of them to fill
+    /// an array. Each element keeps its state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+    /// let chunks = CowIter::from_cow(data)
+    ///     .array_chunks_cow::<2>()
+    ///     .map(|[a, b]| *a + *b)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(chunks, [3, 7]);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn array_chunks_cow<const N: usize>(self) -> ArrayChunksCow<Self, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        ArrayChunksCow { iter: self }
+    }
+
+    /// Splits the iterator into two independent iterators, which both yield
+    /// every element.
+    ///
+    /// When the initial data is borrowed, the underlying iterator is cloned,
+    /// and both halves borrow the elements from the initial data.
+    ///
+    /// When it is owned, the two halves share a buffer. Each element is
+    /// cloned once, so that each half yields an owned element. The elements
+    /// which have been yielded by one half but not yet by the other one are
+    /// kept in the buffer, which may grow up to the size of the data if one
+    /// half is consumed before the other one. Once a half is dropped, the
+    /// other one stops cloning and buffering.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+    /// let (sum, max) = CowIter::from_cow(data).tee_cow();
+    ///
+    /// assert_eq!(sum.map(|e| *e).sum::<u32>(), 6);
+    /// assert_eq!(max.map(Cow::into_owned).max(), Some(3));
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn tee_cow(self) -> (TeeCow<I, Iterr1, Iterr2>, TeeCow<I, Iterr1, Iterr2>)
+    where
+        Iterr1: Clone,
+        <I as ToOwned>::Owned: Clone,
+    {
+        match self {
+            CowIter::Borrowed(it, _) => (
+                TeeCow {
+                    inner: TeeCowInner::Borrowed(it.clone()),
+                },
+                TeeCow {
+                    inner: TeeCowInner::Borrowed(it),
+                },
+            ),
+            CowIter::Owned(it, _) => {
+                let buffer = Rc::new(RefCell::new(TeeBuffer {
+                    iter: it,
+                    backlog: VecDeque::new(),
+                    backlog_owner: false,
+                }));
+
+                (
+                    TeeCow {
+                        inner: TeeCowInner::Owned {
+                            buffer: Rc::clone(&buffer),
+                            id: false,
+                        },
+                    },
+                    TeeCow {
+                        inner: TeeCowInner::Owned { buffer, id: true },
+                    },
+                )
+            }
+        }
+    }
+
+    /// Creates an iterator which skips the `None` elements and unwraps the
+    /// `Some` ones.
+    ///
+    /// The inner value is borrowed when the initial element is borrowed.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[_]> = Cow::Borrowed(&[Some(1), None, Some(3)]);
+    /// let mut iter = CowIter::from_cow(data).flatten_once();
+    ///
+    /// assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+    /// assert_eq!(iter.next(), Some(Cow::Borrowed(&3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn flatten_once(self) -> FlattenOnce<Self> {
+        FlattenOnce { iter: self }
+    }
+
+    /// Creates an iterator which copies all of its elements, removing the
+    /// [`Cow`] wrapper.
+    ///
+    /// This is useful when the elements are small enough, for instance
+    /// integers, so that borrowing them is pointless.
+    ///
+    /// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn copied_cow(self) -> CopiedCow<Self>
+    where
+        I: Copy,
+    {
+        CopiedCow { iter: self }
+    }
+
+    /// Creates an iterator which allows to look at any element ahead without
+    /// consuming it.
+    ///
+    /// The elements which are looked at are buffered, so that they are not
+    /// cloned.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn peekable_nth(self) -> PeekableNth<'a, I, Self> {
+        PeekableNth {
+            iter: self,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Returns the index of the partition point of the remaining elements,
+    /// according to the given predicate.
+    ///
+    /// The elements are assumed to be partitioned according to the predicate:
+    /// every element for which it returns `true` must come before every
+    /// element for which it returns `false`. This is the case, for instance,
+    /// when the elements are sorted and the predicate is `|x| x < value`. If
+    /// the elements are not partitioned, the returned value is unspecified.
+    ///
+    /// The remaining elements are first collected into a temporary buffer, so
+    /// that each probe of the binary search takes constant time. When the
+    /// data is borrowed, the buffer only holds references. When the data is
+    /// owned, the elements are cloned into it.
+    pub fn partition_point_cow<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(&I) -> bool,
+        Iterr1: Clone,
+        Iterr2: Clone,
+    {
+        match self {
+            CowIter::Borrowed(it, _) => {
+                let elements = it.clone().collect::<Vec<_>>();
+                partition_point(elements.len(), |idx| pred(elements[idx]))
+            }
+            CowIter::Owned(it, _) => {
+                let elements = it.clone().collect::<Vec<_>>();
+                partition_point(elements.len(), |idx| pred(elements[idx].borrow()))
+            }
+        }
+    }
 }
 
-impl<'a, Input, I, Iterr1, Iterr2> From<Cow<'a, Input>> for CowIter<'a, I, Input, Iterr1, Iterr2>
+impl<'a, K, V, Input, Iterr1, Iterr2> CowIter<'a, (K, V), Input, Iterr1, Iterr2>
 where
-    I: 'a + ToOwned,
-    Iterr1: Iterator<Item = &'a I>,
-    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
+    K: 'a + Clone,
+    V: 'a + Clone,
+    Iterr1: Iterator<Item = &'a (K, V)>,
+    Iterr2: Iterator<Item = (K, V)>,
     Input: 'a + ToOwned + ?Sized,
-    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
-    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+    &'a Input: IntoIterator<Item = &'a (K, V), IntoIter = Iterr1>,
+    <Input as ToOwned>::Owned: IntoIterator<Item = (K, V), IntoIter = Iterr2>,
 {
-    // TODO: write a test for this function
-    fn from(input: Cow<'a, Input>) -> Self {
-        CowIter::from_cow(input)
+    /// Collects key-value pairs into a `HashMap`.
+    ///
+    /// Keys and values are borrowed if the initial data is borrowed, and moved
+    /// otherwise. If a key appears multiple times, the last value is kept.
+    pub fn collect_into_cow_map(self) -> HashMap<Cow<'a, K>, Cow<'a, V>>
+    where
+        Cow<'a, K>: Eq + Hash,
+    {
+        match self {
+            CowIter::Borrowed(it, _) => it
+                .map(|(k, v)| (Cow::Borrowed(k), Cow::Borrowed(v)))
+                .collect(),
+            CowIter::Owned(it, _) => it.map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))).collect(),
+        }
     }
 }
 
-impl<'a, I, Input, Iterr1, Iterr2> Iterator for CowIter<'a, I, Input, Iterr1, Iterr2>
+impl<'a, T> CowIter<'a, T, Vec<T>, slice::Iter<'a, T>, vec::IntoIter<T>>
 where
-    I: 'a + ToOwned,
-    Iterr1: Iterator<Item = &'a I>,
-    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
-    Input: 'a + ToOwned + ?Sized,
-    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
-    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+    T: 'a + Clone,
 {
-    type Item = Cow<'a, I>;
+    /// Removes the consecutive elements of `data` which map to the same key,
+    /// keeping the first element of each run.
+    ///
+    /// This takes the initial `Cow` instead of a `CowIter`, so that it can be
+    /// returned as is if nothing is removed. Otherwise, the data is cloned if
+    /// needed and returned as `Owned`.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let words = Cow::Owned(vec!["a", "a", "b"]);
+    /// let deduped = CowIter::dedup_by_key_cow(words, |word| *word);
+    /// assert!(matches!(deduped, Cow::Owned(v) if v == ["a", "b"]));
+    ///
+    /// let words = vec!["a", "b"];
+    /// let deduped = CowIter::dedup_by_key_cow(Cow::Borrowed(&words), |word| *word);
+    /// assert!(matches!(deduped, Cow::Borrowed(v) if std::ptr::eq(v, &words)));
+    /// ```
+    pub fn dedup_by_key_cow<K, F>(data: Cow<'a, Vec<T>>, mut key: F) -> Cow<'a, Vec<T>>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let mut last_key = None;
+        let first_removed = data.iter().position(|element| {
+            let current_key = key(element);
 
-    fn next(&mut self) -> Option<Self::Item> {
+            if last_key.as_ref() == Some(&current_key) {
+                true
+            } else {
+                last_key = Some(current_key);
+                false
+            }
+        });
+
+        let first_removed = match first_removed {
+            Some(first_removed) => first_removed,
+            None => return data,
+        };
+
+        let mut elements = data.into_owned();
+        let remaining = elements.split_off(first_removed + 1);
+        elements.pop();
+
+        for element in remaining {
+            let current_key = key(&element);
+
+            if last_key.as_ref() != Some(&current_key) {
+                elements.push(element);
+                last_key = Some(current_key);
+            }
+        }
+
+        Cow::Owned(elements)
+    }
+
+    /// Replaces the elements of `data` matching `pred` with clones of
+    /// `replacement`.
+    ///
+    /// This takes the initial `Cow` instead of a `CowIter`, so that it can be
+    /// returned as is if no element matches. Otherwise, the data is cloned if
+    /// needed and returned as `Owned`.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let numbers = vec![0, 1, 0];
+    /// let replaced = CowIter::replace_all_cow(Cow::Borrowed(&numbers), |x| *x == 0, -1);
+    /// assert!(matches!(replaced, Cow::Owned(v) if v == [-1, 1, -1]));
+    ///
+    /// let replaced = CowIter::replace_all_cow(Cow::Borrowed(&numbers), |x| *x == 2, -1);
+    /// assert!(matches!(replaced, Cow::Borrowed(v) if std::ptr::eq(v, &numbers)));
+    /// ```
+    pub fn replace_all_cow<F>(data: Cow<'a, Vec<T>>, mut pred: F, replacement: T) -> Cow<'a, Vec<T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let first = match data.iter().position(&mut pred) {
+            Some(first) => first,
+            None => return data,
+        };
+
+        let mut elements = data.into_owned();
+        elements[first] = replacement.clone();
+
+        for element in &mut elements[first + 1..] {
+            if pred(element) {
+                *element = replacement.clone();
+            }
+        }
+
+        Cow::Owned(elements)
+    }
+}
+
+impl<'a, T, E, Input, Iterr1, Iterr2> CowIter<'a, Result<T, E>, Input, Iterr1, Iterr2>
+where
+    T: 'a + Clone,
+    E: 'a + Clone,
+    Iterr1: Iterator<Item = &'a Result<T, E>>,
+    Iterr2: Iterator<Item = Result<T, E>>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a Result<T, E>, IntoIter = Iterr1>,
+    <Input as ToOwned>::Owned: IntoIterator<Item = Result<T, E>, IntoIter = Iterr2>,
+{
+    /// Collects the successful values, stopping at the first error.
+    ///
+    /// The values are borrowed if the initial data is borrowed, and moved
+    /// otherwise. The error is cloned if it is borrowed.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use butcher::iterator::CowIter;
+    ///
+    /// let data: Cow<[Result<u32, String>]> = Cow::Borrowed(&[Ok(1), Ok(2)]);
+    /// let values = CowIter::from_cow(data).collect_result_cow();
+    /// assert_eq!(values, Ok(vec![Cow::Borrowed(&1), Cow::Borrowed(&2)]));
+    ///
+    /// let data: Cow<[Result<u32, String>]> = Cow::Owned(vec![Ok(1), Err("nope".to_string())]);
+    /// let values = CowIter::from_cow(data).collect_result_cow();
+    /// assert_eq!(values, Err("nope".to_string()));
+    /// ```
+    pub fn collect_result_cow(self) -> Result<Vec<Cow<'a, T>>, E> {
         match self {
-            CowIter::Borrowed(it, _) => it.next().map(Cow::Borrowed),
-            CowIter::Owned(it, _) => it.next().map(Cow::Owned),
+            CowIter::Borrowed(it, _) => it
+                .map(|element| match element {
+                    Ok(value) => Ok(Cow::Borrowed(value)),
+                    Err(e) => Err(e.clone()),
+                })
+                .collect(),
+            CowIter::Owned(it, _) => it.map(|element| element.map(Cow::Owned)).collect(),
         }
     }
 }
 
-/// Allows to convert any cow containing an iterator into a `CowIter`.
+/// An iterator which maps the elements of a [`CowIter`] until the mapping
+/// function returns `None`.
 ///
-/// This trait provides better method-chaining, but is just a simple wrapper
-/// for [`CowIter::from_cow`].
+/// This struct is created by [`CowIter::map_while_cow`].
 ///
-/// [`CowIter::from_cow`]: enum.CowIter.html#method.from_cow
-pub trait IntoCowIterator {
-    type Item: ToOwned;
-    type IntoIter: Iterator;
-
-    fn into_cow_iter(self) -> Self::IntoIter;
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::map_while_cow`]: enum.CowIter.html#method.map_while_cow
+pub struct MapWhileCow<It, F> {
+    iter: It,
+    f: F,
+    done: bool,
 }
 
-impl<'a, Input, I, Iterr1, Iterr2> IntoCowIterator for Cow<'a, Input>
+impl<'a, It, F, I, B> Iterator for MapWhileCow<It, F>
 where
-    I: 'a + ToOwned,
-    Iterr1: Iterator<Item = &'a I>,
-    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
-    Input: 'a + ToOwned + ?Sized,
-    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
-    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+    F: FnMut(Cow<'a, I>) -> Option<B>,
 {
-    type Item = I;
-    type IntoIter = CowIter<'a, I, Input, Iterr1, Iterr2>;
+    type Item = B;
 
-    // TODO: write a test for this function
-    fn into_cow_iter(self) -> Self::IntoIter {
-        CowIter::from_cow(self)
+    fn next(&mut self) -> Option<B> {
+        if self.done {
+            return None;
+        }
+
+        let mapped = self.iter.next().and_then(&mut self.f);
+        self.done = mapped.is_none();
+        mapped
     }
 }
 
-#[cfg(test)]
-mod cow_iter {
-    use super::*;
+/// An iterator which maps the elements of a [`CowIter`] from back to front.
+///
+/// This struct is created by [`CowIter::rev_map_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::rev_map_cow`]: enum.CowIter.html#method.rev_map_cow
+pub struct RevMapCow<It, F> {
+    iter: It,
+    f: F,
+}
 
-    #[test]
-    fn iterator_owned() {
-        let numbers: Cow<[_]> = Cow::Owned(vec![0, 1, 1, 2, 3, 5]);
-        let mut iter = CowIter::from_cow(numbers);
+impl<'a, It, F, I, B> Iterator for RevMapCow<It, F>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: DoubleEndedIterator<Item = Cow<'a, I>>,
+    F: FnMut(Cow<'a, I>) -> B,
+{
+    type Item = B;
 
-        assert_eq!(iter.next(), Some(Cow::Owned(0)));
-        assert_eq!(iter.next(), Some(Cow::Owned(1)));
-        assert_eq!(iter.next(), Some(Cow::Owned(1)));
-        assert_eq!(iter.next(), Some(Cow::Owned(2)));
-        assert_eq!(iter.next(), Some(Cow::Owned(3)));
-        assert_eq!(iter.next(), Some(Cow::Owned(5)));
-        assert_eq!(iter.next(), None);
+    fn next(&mut self) -> Option<B> {
+        self.iter.next_back().map(&mut self.f)
     }
 
-    #[test]
-    fn iterator_borrowed() {
-        let numbers: Cow<[_]> = Cow::Borrowed(&[0, 1, 1, 2, 3, 5] as &[_]);
-        let mut iter = CowIter::from_cow(numbers);
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
 
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&0)));
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&2)));
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&3)));
-        assert_eq!(iter.next(), Some(Cow::Borrowed(&5)));
+impl<'a, It, F, I, B> DoubleEndedIterator for RevMapCow<It, F>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: DoubleEndedIterator<Item = Cow<'a, I>>,
+    F: FnMut(Cow<'a, I>) -> B,
+{
+    fn next_back(&mut self) -> Option<B> {
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+impl<'a, It, F, I, B> ExactSizeIterator for RevMapCow<It, F>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: DoubleEndedIterator<Item = Cow<'a, I>> + ExactSizeIterator,
+    F: FnMut(Cow<'a, I>) -> B,
+{
+}
+
+/// An iterator which produces each of its values from as many elements of a
+/// [`CowIter`] as needed.
+///
+/// This struct is created by [`CowIter::batching_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::batching_cow`]: enum.CowIter.html#method.batching_cow
+pub struct BatchingCow<It, F> {
+    iter: It,
+    f: F,
+}
+
+impl<It, F, B> Iterator for BatchingCow<It, F>
+where
+    F: FnMut(&mut It) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        (self.f)(&mut self.iter)
+    }
+}
+
+/// An iterator which copies the elements of a [`CowIter`].
+///
+/// This struct is created by [`CowIter::copied_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::copied_cow`]: enum.CowIter.html#method.copied_cow
+pub struct CopiedCow<It> {
+    iter: It,
+}
+
+impl<'a, It, I> Iterator for CopiedCow<It>
+where
+    I: 'a + Copy,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<I> {
+        self.iter.next().map(|element| *element)
+    }
+}
+
+/// An iterator which yields the first elements of a [`CowIter`].
+///
+/// This struct is created by [`CowIter::take_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::take_cow`]: enum.CowIter.html#method.take_cow
+pub struct TakeCow<It> {
+    iter: Take<It>,
+}
+
+impl<It> Iterator for TakeCow<It>
+where
+    It: Iterator,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<It::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<It> DoubleEndedIterator for TakeCow<It>
+where
+    It: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<It::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<It> ExactSizeIterator for TakeCow<It> where It: ExactSizeIterator {}
+
+/// An iterator which skips the first elements of a [`CowIter`].
+///
+/// This struct is created by [`CowIter::skip_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::skip_cow`]: enum.CowIter.html#method.skip_cow
+pub struct SkipCow<It> {
+    iter: Skip<It>,
+}
+
+impl<It> Iterator for SkipCow<It>
+where
+    It: Iterator,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<It::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<It> DoubleEndedIterator for SkipCow<It>
+where
+    It: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<It::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<It> ExactSizeIterator for SkipCow<It> where It: ExactSizeIterator {}
+
+/// An iterator which yields the elements of a [`CowIter`] along with their
+/// index, starting at a given value.
+///
+/// This struct is created by [`CowIter::enumerate_from_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::enumerate_from_cow`]: enum.CowIter.html#method.enumerate_from_cow
+pub struct EnumerateFromCow<It> {
+    iter: It,
+    count: usize,
+}
+
+impl<It> Iterator for EnumerateFromCow<It>
+where
+    It: Iterator,
+{
+    type Item = (usize, It::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.iter.next()?;
+        let idx = self.count;
+        self.count += 1;
+
+        Some((idx, element))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<It> ExactSizeIterator for EnumerateFromCow<It> where It: ExactSizeIterator {}
+
+/// An iterator which yields the elements of two [`CowIter`] alternately.
+///
+/// This struct is created by [`CowIter::interleave_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::interleave_cow`]: enum.CowIter.html#method.interleave_cow
+pub struct InterleaveCow<It1, It2> {
+    first: Fuse<It1>,
+    second: Fuse<It2>,
+    second_next: bool,
+}
+
+impl<It1, It2> Iterator for InterleaveCow<It1, It2>
+where
+    It1: Iterator,
+    It2: Iterator<Item = It1::Item>,
+{
+    type Item = It1::Item;
+
+    fn next(&mut self) -> Option<It1::Item> {
+        self.second_next = !self.second_next;
+
+        if self.second_next {
+            self.first.next().or_else(|| self.second.next())
+        } else {
+            self.second.next().or_else(|| self.first.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_low, first_high) = self.first.size_hint();
+        let (second_low, second_high) = self.second.size_hint();
+
+        let low = first_low.saturating_add(second_low);
+        let high = match (first_high, second_high) {
+            (Some(first), Some(second)) => first.checked_add(second),
+            _ => None,
+        };
+
+        (low, high)
+    }
+}
+
+/// A fused iterator over the elements of a [`CowIter`], which records
+/// whether any of them was owned.
+///
+/// This struct is created by [`CowIter::fuse_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::fuse_cow`]: enum.CowIter.html#method.fuse_cow
+pub struct FuseCow<It> {
+    iter: Fuse<It>,
+    any_owned: bool,
+}
+
+impl<It> FuseCow<It> {
+    /// Returns whether any of the elements yielded so far was owned.
+    pub fn any_owned(&self) -> bool {
+        self.any_owned
+    }
+}
+
+impl<'a, It, I> Iterator for FuseCow<It>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+    type Item = Cow<'a, I>;
+
+    fn next(&mut self) -> Option<Cow<'a, I>> {
+        let element = self.iter.next()?;
+        self.any_owned |= matches!(element, Cow::Owned(_));
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, It, I> FusedIterator for FuseCow<It>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+}
+
+/// An iterator over the elements of a [`CowIter`], in arrays of `N` elements.
+///
+/// This struct is created by [`CowIter::array_chunks_cow`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::array_chunks_cow`]: enum.CowIter.html#method.array_chunks_cow
+pub struct ArrayChunksCow<It, const N: usize> {
+    iter: It,
+}
+
+impl<'a, It, I, const N: usize> Iterator for ArrayChunksCow<It, N>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+    type Item = [Cow<'a, I>; N];
+
+    fn next(&mut self) -> Option<[Cow<'a, I>; N]> {
+        let chunk = self.iter.by_ref().take(N).collect::<Vec<_>>();
+        chunk.try_into().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (low / N, high.map(|high| high / N))
+    }
+}
+
+/// One of the two iterators created by [`CowIter::tee_cow`].
+///
+/// [`CowIter::tee_cow`]: enum.CowIter.html#method.tee_cow
+pub struct TeeCow<I, Iterr1, Iterr2>
+where
+    I: ToOwned,
+{
+    inner: TeeCowInner<I, Iterr1, Iterr2>,
+}
+
+enum TeeCowInner<I, Iterr1, Iterr2>
+where
+    I: ToOwned,
+{
+    Borrowed(Iterr1),
+    Owned {
+        buffer: Rc<RefCell<TeeBuffer<<I as ToOwned>::Owned, Iterr2>>>,
+        id: bool,
+    },
+}
+
+/// The state shared by the two halves of an owned [`TeeCow`].
+///
+/// [`TeeCow`]: struct.TeeCow.html
+struct TeeBuffer<T, It> {
+    iter: It,
+    /// The elements which have been yielded by one half only.
+    backlog: VecDeque<T>,
+    /// The id of the half which has not yielded the elements of `backlog` yet.
+    backlog_owner: bool,
+}
+
+impl<'a, I, Iterr1, Iterr2> Iterator for TeeCow<I, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    <I as ToOwned>::Owned: Clone,
+    Iterr1: Iterator<Item = &'a I>,
+    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
+{
+    type Item = Cow<'a, I>;
+
+    fn next(&mut self) -> Option<Cow<'a, I>> {
+        match &mut self.inner {
+            TeeCowInner::Borrowed(it) => it.next().map(Cow::Borrowed),
+            TeeCowInner::Owned { buffer, id } => {
+                let other_alive = Rc::strong_count(buffer) > 1;
+                let mut buffer = buffer.borrow_mut();
+
+                if buffer.backlog_owner == *id {
+                    if let Some(element) = buffer.backlog.pop_front() {
+                        return Some(Cow::Owned(element));
+                    }
+                }
+
+                let element = buffer.iter.next()?;
+                if other_alive {
+                    buffer.backlog.push_back(element.clone());
+                    buffer.backlog_owner = !*id;
+                }
+
+                Some(Cow::Owned(element))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            TeeCowInner::Borrowed(it) => it.size_hint(),
+            TeeCowInner::Owned { buffer, id } => {
+                let buffer = RefCell::borrow(buffer);
+                let buffered = if buffer.backlog_owner == *id {
+                    buffer.backlog.len()
+                } else {
+                    0
+                };
+
+                let (low, high) = buffer.iter.size_hint();
+                (
+                    low.saturating_add(buffered),
+                    high.and_then(|high| high.checked_add(buffered)),
+                )
+            }
+        }
+    }
+}
+
+impl<'a, I, Iterr1, Iterr2> ExactSizeIterator for TeeCow<I, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    <I as ToOwned>::Owned: Clone,
+    Iterr1: ExactSizeIterator<Item = &'a I>,
+    Iterr2: ExactSizeIterator<Item = <I as ToOwned>::Owned>,
+{
+}
+
+/// An iterator which skips the `None` elements of a [`CowIter`] and unwraps
+/// the `Some` ones.
+///
+/// This struct is created by [`CowIter::flatten_once`].
+///
+/// [`CowIter`]: enum.CowIter.html
+/// [`CowIter::flatten_once`]: enum.CowIter.html#method.flatten_once
+pub struct FlattenOnce<It> {
+    iter: It,
+}
+
+impl<'a, It, T> Iterator for FlattenOnce<It>
+where
+    T: 'a + Clone,
+    It: Iterator<Item = Cow<'a, Option<T>>>,
+{
+    type Item = Cow<'a, T>;
+
+    fn next(&mut self) -> Option<Cow<'a, T>> {
+        loop {
+            match self.iter.next()? {
+                Cow::Borrowed(Some(element)) => return Some(Cow::Borrowed(element)),
+                Cow::Owned(Some(element)) => return Some(Cow::Owned(element)),
+                Cow::Borrowed(None) | Cow::Owned(None) => {}
+            }
+        }
+    }
+}
+
+/// An iterator with a `peek_nth` method, which allows to look at any element
+/// ahead without consuming it.
+///
+/// This struct is created by [`CowIter::peekable_nth`].
+///
+/// [`CowIter::peekable_nth`]: enum.CowIter.html#method.peekable_nth
+pub struct PeekableNth<'a, I, It>
+where
+    I: 'a + ToOwned + ?Sized,
+{
+    iter: It,
+    buffer: VecDeque<Cow<'a, I>>,
+}
+
+impl<'a, I, It> PeekableNth<'a, I, It>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+    /// Returns a reference to the `n`-th next element, without consuming any
+    /// element.
+    ///
+    /// `peek_nth(0)` returns the element which will be returned by the next
+    /// call to `next`. Returns `None` if there are not enough elements left.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I> {
+        while self.buffer.len() <= n {
+            let element = self.iter.next()?;
+            self.buffer.push_back(element);
+        }
+
+        self.buffer.get(n).map(Cow::as_ref)
+    }
+}
+
+impl<'a, I, It> Iterator for PeekableNth<'a, I, It>
+where
+    I: 'a + ToOwned + ?Sized,
+    It: Iterator<Item = Cow<'a, I>>,
+{
+    type Item = Cow<'a, I>;
+
+    fn next(&mut self) -> Option<Cow<'a, I>> {
+        self.buffer.pop_front().or_else(|| self.iter.next())
+    }
+}
+
+/// Performs a binary search over the indexes lower than `len`, returning the
+/// first index for which `pred` returns `false`.
+fn partition_point<F>(len: usize, mut pred: F) -> usize
+where
+    F: FnMut(usize) -> bool,
+{
+    let (mut low, mut high) = (0, len);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+impl<'a, Input, I, Iterr1, Iterr2> From<Cow<'a, Input>> for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: Iterator<Item = &'a I>,
+    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+    // TODO: write a test for this function
+    fn from(input: Cow<'a, Input>) -> Self {
+        CowIter::from_cow(input)
+    }
+}
+
+impl<'a, I, Input, Iterr1, Iterr2> Iterator for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: Iterator<Item = &'a I>,
+    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+    type Item = Cow<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            CowIter::Borrowed(it, _) => it.next().map(Cow::Borrowed),
+            CowIter::Owned(it, _) => it.next().map(Cow::Owned),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            CowIter::Borrowed(it, _) => it.size_hint(),
+            CowIter::Owned(it, _) => it.size_hint(),
+        }
+    }
+}
+
+impl<'a, I, Input, Iterr1, Iterr2> DoubleEndedIterator for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: DoubleEndedIterator<Item = &'a I>,
+    Iterr2: DoubleEndedIterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            CowIter::Borrowed(it, _) => it.next_back().map(Cow::Borrowed),
+            CowIter::Owned(it, _) => it.next_back().map(Cow::Owned),
+        }
+    }
+}
+
+impl<'a, I, Input, Iterr1, Iterr2> ExactSizeIterator for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: ExactSizeIterator<Item = &'a I>,
+    Iterr2: ExactSizeIterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+}
+
+impl<'a, I, Input, Iterr1, Iterr2> FusedIterator for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: FusedIterator<Item = &'a I>,
+    Iterr2: FusedIterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+}
+
+impl<'a, I, Input, Iterr1, Iterr2> Clone for CowIter<'a, I, Input, Iterr1, Iterr2>
+where
+    I: 'a + ToOwned,
+    Iterr1: Iterator<Item = &'a I> + Clone,
+    Iterr2: Iterator<Item = <I as ToOwned>::Owned> + Clone,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+    fn clone(&self) -> Self {
+        match self {
+            CowIter::Borrowed(it, _) => CowIter::Borrowed(it.clone(), PhantomData),
+            CowIter::Owned(it, _) => CowIter::Owned(it.clone(), PhantomData),
+        }
+    }
+}
+
+/// Allows to convert any cow containing an iterator into a `CowIter`.
+///
+/// This trait provides better method-chaining, but is just a simple wrapper
+/// for [`CowIter::from_cow`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use butcher::iterator::{CowIter, IntoCowIterator};
+///
+/// fn sum_numbers(elems: Cow<[u32]>) -> u32 {
+///     let iter: CowIter<_, _, _, _> = elems.into_cow_iter();
+///
+///     iter.map(|element: Cow<u32>| *element).sum()
+/// }
+///
+/// assert_eq!(sum_numbers(Cow::Borrowed(&[1, 2, 3])), 6);
+/// assert_eq!(sum_numbers(Cow::Owned(vec![4, 5])), 9);
+/// ```
+///
+/// [`CowIter::from_cow`]: enum.CowIter.html#method.from_cow
+pub trait IntoCowIterator {
+    type Item: ToOwned;
+    type IntoIter: Iterator;
+
+    /// Creates an iterator over the elements, which are borrowed if the
+    /// collection is borrowed, and owned otherwise.
+    fn into_cow_iter(self) -> Self::IntoIter;
+}
+
+impl<'a, Input, I, Iterr1, Iterr2> IntoCowIterator for Cow<'a, Input>
+where
+    I: 'a + ToOwned,
+    Iterr1: Iterator<Item = &'a I>,
+    Iterr2: Iterator<Item = <I as ToOwned>::Owned>,
+    Input: 'a + ToOwned + ?Sized,
+    &'a Input: IntoIterator<Item = &'a I, IntoIter = Iterr1> + ToOwned,
+    <Input as ToOwned>::Owned: IntoIterator<Item = <I as ToOwned>::Owned, IntoIter = Iterr2>,
+{
+    type Item = I;
+    type IntoIter = CowIter<'a, I, Input, Iterr1, Iterr2>;
+
+    fn into_cow_iter(self) -> Self::IntoIter {
+        CowIter::from_cow(self)
+    }
+}
+
+/// An iterator over the entries of a map wrapped in a `Cow`.
+///
+/// The keys and the values are borrowed if the map is borrowed, and moved out
+/// of it otherwise. Iterating over a borrowed map never clones its entries.
+///
+/// It is created by [`IntoCowMapIterator::into_cow_map_iter`].
+///
+/// [`IntoCowMapIterator::into_cow_map_iter`]: trait.IntoCowMapIterator.html#tymethod.into_cow_map_iter
+pub enum CowMapIter<Iterr1, Iterr2> {
+    Borrowed(Iterr1),
+    Owned(Iterr2),
+}
+
+impl<'a, K, V, Iterr1, Iterr2> Iterator for CowMapIter<Iterr1, Iterr2>
+where
+    K: 'a + Clone,
+    V: 'a + Clone,
+    Iterr1: Iterator<Item = (&'a K, &'a V)>,
+    Iterr2: Iterator<Item = (K, V)>,
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            CowMapIter::Borrowed(it) => {
+                it.next().map(|(k, v)| (Cow::Borrowed(k), Cow::Borrowed(v)))
+            }
+            CowMapIter::Owned(it) => it.next().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            CowMapIter::Borrowed(it) => it.size_hint(),
+            CowMapIter::Owned(it) => it.size_hint(),
+        }
+    }
+}
+
+impl<'a, K, V, Iterr1, Iterr2> DoubleEndedIterator for CowMapIter<Iterr1, Iterr2>
+where
+    K: 'a + Clone,
+    V: 'a + Clone,
+    Iterr1: DoubleEndedIterator<Item = (&'a K, &'a V)>,
+    Iterr2: DoubleEndedIterator<Item = (K, V)>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            CowMapIter::Borrowed(it) => it
+                .next_back()
+                .map(|(k, v)| (Cow::Borrowed(k), Cow::Borrowed(v))),
+            CowMapIter::Owned(it) => it.next_back().map(|(k, v)| (Cow::Owned(k), Cow::Owned(v))),
+        }
+    }
+}
+
+impl<'a, K, V, Iterr1, Iterr2> ExactSizeIterator for CowMapIter<Iterr1, Iterr2>
+where
+    K: 'a + Clone,
+    V: 'a + Clone,
+    Iterr1: ExactSizeIterator<Item = (&'a K, &'a V)>,
+    Iterr2: ExactSizeIterator<Item = (K, V)>,
+{
+}
+
+/// Allows to convert a map wrapped in a `Cow` into a [`CowMapIter`].
+///
+/// Maps can't be iterated with [`CowIter`], as iterating over a borrowed map
+/// yields pairs of references rather than references to pairs.
+///
+/// It is implemented for `HashMap` and `BTreeMap`. The entries of a `BTreeMap`
+/// are yielded in ascending key order, and can be iterated from the back.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use std::collections::HashMap;
+/// use butcher::iterator::IntoCowMapIterator;
+///
+/// let mut ages = HashMap::new();
+/// ages.insert("Ada".to_string(), 36);
+///
+/// let borrowed: Cow<HashMap<_, _>> = Cow::Borrowed(&ages);
+/// for (name, age) in borrowed.into_cow_map_iter() {
+///     assert!(matches!((name, age), (Cow::Borrowed(_), Cow::Borrowed(36))));
+/// }
+///
+/// let owned: Cow<HashMap<_, _>> = Cow::Owned(ages);
+/// for (name, age) in owned.into_cow_map_iter() {
+///     assert!(matches!((name, age), (Cow::Owned(_), Cow::Owned(36))));
+/// }
+/// ```
+///
+/// [`CowMapIter`]: enum.CowMapIter.html
+/// [`CowIter`]: enum.CowIter.html
+pub trait IntoCowMapIterator {
+    type Key: ToOwned;
+    type Value: ToOwned;
+    type IntoIter: Iterator;
+
+    /// Creates an iterator over the entries, which are borrowed if the map is
+    /// borrowed, and owned otherwise.
+    fn into_cow_map_iter(self) -> Self::IntoIter;
+}
+
+impl<'a, K, V, S> IntoCowMapIterator for Cow<'a, HashMap<K, V, S>>
+where
+    K: 'a + Clone,
+    V: 'a + Clone,
+    S: 'a + Clone,
+{
+    type Key = K;
+    type Value = V;
+    type IntoIter = CowMapIter<hash_map::Iter<'a, K, V>, hash_map::IntoIter<K, V>>;
+
+    fn into_cow_map_iter(self) -> Self::IntoIter {
+        match self {
+            Cow::Borrowed(map) => CowMapIter::Borrowed(map.iter()),
+            Cow::Owned(map) => CowMapIter::Owned(map.into_iter()),
+        }
+    }
+}
+
+impl<'a, K, V> IntoCowMapIterator for Cow<'a, BTreeMap<K, V>>
+where
+    K: 'a + Clone,
+    V: 'a + Clone,
+{
+    type Key = K;
+    type Value = V;
+    type IntoIter = CowMapIter<btree_map::Iter<'a, K, V>, btree_map::IntoIter<K, V>>;
+
+    fn into_cow_map_iter(self) -> Self::IntoIter {
+        match self {
+            Cow::Borrowed(map) => CowMapIter::Borrowed(map.iter()),
+            Cow::Owned(map) => CowMapIter::Owned(map.into_iter()),
+        }
+    }
+}
+
+/// Collects the elements yielded by a [`CowIter`] back into a `Cow<Vec<T>>`.
+///
+/// The result is `Borrowed` only if every item is borrowed, and if the items
+/// are all the elements of `source`, in order. This is the case when they
+/// come unchanged from a `CowIter` over `source`. A `Vec` can't be borrowed
+/// partially, so the result is `Owned` if some elements were skipped. In this
+/// case, each item is converted to its owned form.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use butcher::iterator::{collect_cow, CowIter};
+///
+/// let source = vec![1, 2, 3, 4];
+///
+/// let items = CowIter::from_cow(Cow::Borrowed(&source));
+/// assert!(matches!(collect_cow(&source, items), Cow::Borrowed(v) if std::ptr::eq(v, &source)));
+///
+/// let items = CowIter::from_cow(Cow::Borrowed(&source)).step_by(2);
+/// assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v == [1, 3]));
+/// ```
+///
+/// [`CowIter`]: enum.CowIter.html
+pub fn collect_cow<'a, T, It>(source: &'a Vec<T>, items: It) -> Cow<'a, Vec<T>>
+where
+    T: Clone,
+    It: IntoIterator<Item = Cow<'a, T>>,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+
+    let borrowed = items
+        .iter()
+        .map(|item| match item {
+            Cow::Borrowed(item) => Some(*item),
+            Cow::Owned(_) => None,
+        })
+        .collect::<Option<Vec<_>>>();
+
+    if let Some(borrowed) = borrowed {
+        if borrows_all(source, &borrowed) {
+            return Cow::Borrowed(source);
+        }
+    }
+
+    Cow::Owned(items.into_iter().map(Cow::into_owned).collect())
+}
+
+/// Returns whether `items` are all the elements of `source`, in order.
+///
+/// The elements of a zero-sized type may all have the same address, so only
+/// the number of items is checked for them.
+fn borrows_all<T>(source: &[T], items: &[&T]) -> bool {
+    items.len() == source.len()
+        && (mem::size_of::<T>() == 0
+            || source
+                .iter()
+                .zip(items)
+                .all(|(element, item)| ptr::eq(element, *item)))
+}
+
+#[cfg(test)]
+mod cow_map_iter {
+    use super::*;
+
+    /// A type which can't be cloned without panicking.
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub(super) struct NoClone(pub(super) u32);
+
+    impl Clone for NoClone {
+        fn clone(&self) -> NoClone {
+            panic!("NoClone was cloned")
+        }
+    }
+
+    #[test]
+    fn hash_map_borrowed() {
+        let mut map = HashMap::new();
+        map.insert(NoClone(1), NoClone(10));
+        map.insert(NoClone(2), NoClone(20));
+
+        let mut entries = Cow::Borrowed(&map)
+            .into_cow_map_iter()
+            .map(|(k, v)| match (k, v) {
+                (Cow::Borrowed(k), Cow::Borrowed(v)) => (k.0, v.0),
+                _ => panic!("the entry is not borrowed"),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable();
+
+        assert_eq!(entries, [(1, 10), (2, 20)]);
+    }
+
+    #[test]
+    fn hash_map_owned() {
+        let mut map = HashMap::new();
+        map.insert(NoClone(1), NoClone(10));
+
+        let mut iter = Cow::<HashMap<_, _>>::Owned(map).into_cow_map_iter();
+        assert_eq!(iter.len(), 1);
+
+        let entry = iter.next();
+        assert!(matches!(
+            entry,
+            Some((Cow::Owned(NoClone(1)), Cow::Owned(NoClone(10))))
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn btree_map_borrowed_is_sorted() {
+        let mut map = BTreeMap::new();
+        map.insert(3, "three".to_string());
+        map.insert(1, "one".to_string());
+        map.insert(2, "two".to_string());
+
+        let entries = Cow::Borrowed(&map)
+            .into_cow_map_iter()
+            .map(|(k, v)| match (k, v) {
+                (Cow::Borrowed(k), Cow::Borrowed(v)) => (*k, v.as_str()),
+                _ => panic!("the entry is not borrowed"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(entries, [(1, "one"), (2, "two"), (3, "three")]);
+    }
+
+    #[test]
+    fn btree_map_owned_rev() {
+        let mut map = BTreeMap::new();
+        map.insert(1, 'a');
+        map.insert(2, 'b');
+
+        let mut iter = Cow::<BTreeMap<_, _>>::Owned(map).into_cow_map_iter();
+
+        assert_eq!(iter.next_back(), Some((Cow::Owned(2), Cow::Owned('b'))));
+        assert_eq!(iter.next_back(), Some((Cow::Owned(1), Cow::Owned('a'))));
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[cfg(test)]
+mod cow_iter {
+    use super::*;
+
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn iterator_owned() {
+        let numbers: Cow<[_]> = Cow::Owned(vec![0, 1, 1, 2, 3, 5]);
+        let mut iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.next(), Some(Cow::Owned(0)));
+        assert_eq!(iter.next(), Some(Cow::Owned(1)));
+        assert_eq!(iter.next(), Some(Cow::Owned(1)));
+        assert_eq!(iter.next(), Some(Cow::Owned(2)));
+        assert_eq!(iter.next(), Some(Cow::Owned(3)));
+        assert_eq!(iter.next(), Some(Cow::Owned(5)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iterator_borrowed() {
+        let numbers: Cow<[_]> = Cow::Borrowed(&[0, 1, 1, 2, 3, 5] as &[_]);
+        let mut iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&0)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&2)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&3)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&5)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn reduce_cow_keeps_borrowing() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        let max = CowIter::from_cow(numbers).reduce_cow(|a, b| if b > a { b } else { a });
+
+        assert!(matches!(max, Some(Cow::Borrowed(&9))));
+    }
+
+    #[test]
+    fn reduce_cow_empty() {
+        let numbers: Cow<[u32]> = Cow::Owned(Vec::new());
+        let max = CowIter::from_cow(numbers).reduce_cow(|a, b| if b > a { b } else { a });
+
+        assert_eq!(max, None);
+    }
+
+    #[test]
+    fn max_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[3, 1, 4, 1, 5, 9, 2, 6]);
+        let max = CowIter::from_cow(numbers).max_cow();
+
+        assert!(matches!(max, Some(Cow::Borrowed(&9))));
+    }
+
+    #[test]
+    fn min_cow_owned() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![3, 1, 4, 1, 5, 9, 2, 6]);
+        let min = CowIter::from_cow(numbers).min_cow();
+
+        assert!(matches!(min, Some(Cow::Owned(1))));
+    }
+
+    #[test]
+    fn min_max_cow_ties() {
+        let numbers: &[u32] = &[7, 7, 7];
+
+        let max = CowIter::from_cow(Cow::Borrowed(numbers)).max_cow().unwrap();
+        let min = CowIter::from_cow(Cow::Borrowed(numbers)).min_cow().unwrap();
+
+        // Just like std, the last maximum and the first minimum are returned.
+        assert!(std::ptr::eq(max.as_ref(), &numbers[2]));
+        assert!(std::ptr::eq(min.as_ref(), &numbers[0]));
+    }
+
+    #[test]
+    fn rev_collect_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let reversed = CowIter::from_cow(numbers).rev_collect_cow();
+
+        assert!(matches!(reversed, Cow::Owned(_)));
+        assert_eq!(reversed, Cow::<Vec<u32>>::Owned(vec![3, 2, 1]));
+    }
+
+    #[test]
+    fn peekable_nth_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4]);
+        let mut iter = CowIter::from_cow(numbers).peekable_nth();
+
+        assert_eq!(iter.peek_nth(2), Some(&3));
+        assert_eq!(iter.peek_nth(0), Some(&1));
+        assert_eq!(iter.peek_nth(4), None);
+
+        assert!(matches!(iter.next(), Some(Cow::Borrowed(&1))));
+        assert_eq!(iter.peek_nth(2), Some(&4));
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            [Cow::Borrowed(&2), Cow::Borrowed(&3), Cow::Borrowed(&4)]
+        );
+    }
+
+    #[test]
+    fn extend_into_vec() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[3, 4]);
+        let mut target = vec![1, 2];
+
+        CowIter::from_cow(numbers).extend_into(&mut target);
+
+        assert_eq!(target, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_into_hash_set() {
+        let words: Cow<[String]> = Cow::Owned(vec!["foo".to_string(), "bar".to_string()]);
+        let mut target = std::collections::HashSet::new();
+        target.insert("foo".to_string());
+
+        CowIter::from_cow(words).extend_into(&mut target);
+
+        assert_eq!(target.len(), 2);
+        assert!(target.contains("bar"));
+    }
+
+    #[test]
+    fn is_sorted_cow() {
+        let sorted: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let unsorted: Cow<[u32]> = Cow::Borrowed(&[3, 1, 2]);
+
+        assert!(CowIter::from_cow(sorted).is_sorted_cow());
+        assert!(!CowIter::from_cow(unsorted).is_sorted_cow());
+    }
+
+    #[test]
+    fn is_sorted_by_cow_incomparable() {
+        // Equal elements are considered incomparable here.
+        let compare = |a: &u32, b: &u32| if a == b { None } else { a.partial_cmp(b) };
+
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 2, 3]);
+        let empty: Cow<[u32]> = Cow::Borrowed(&[]);
+
+        assert!(!CowIter::from_cow(numbers).is_sorted_by_cow(compare));
+        assert!(CowIter::from_cow(empty).is_sorted_by_cow(compare));
+    }
+
+    #[test]
+    fn sum_by_len() {
+        let words: Cow<[String]> = Cow::Owned(vec![String::from("foo"), String::from("quux")]);
+        let total: usize = CowIter::from_cow(words).sum_by(String::len);
+
+        assert_eq!(total, 7);
+    }
+
+    #[test]
+    fn dedup_by_key_cow_first_field() {
+        let pairs = vec![("a", 1), ("a", 2), ("b", 3)];
+        let deduped = CowIter::dedup_by_key_cow(Cow::Borrowed(&pairs), |pair| pair.0);
+
+        assert!(matches!(deduped, Cow::Owned(_)));
+        assert_eq!(deduped.as_ref(), &[("a", 1), ("b", 3)]);
+    }
+
+    #[test]
+    fn dedup_by_key_cow_nothing_removed() {
+        let pairs = vec![("a", 1), ("b", 2), ("a", 3)];
+        let deduped = CowIter::dedup_by_key_cow(Cow::Borrowed(&pairs), |pair| pair.0);
+
+        let deduped: &Vec<_> = match deduped {
+            Cow::Borrowed(deduped) => deduped,
+            Cow::Owned(_) => panic!("nothing was removed"),
+        };
+        assert!(ptr::eq(deduped, &pairs));
+    }
+
+    #[test]
+    fn dedup_by_key_cow_several_runs() {
+        let pairs = Cow::Owned(vec![("a", 1), ("a", 2), ("b", 3), ("b", 4), ("a", 5)]);
+        let deduped = CowIter::dedup_by_key_cow(pairs, |pair| pair.0);
+
+        assert!(matches!(deduped, Cow::Owned(v) if v == [("a", 1), ("b", 3), ("a", 5)]));
+    }
+
+    #[test]
+    fn collect_into_cow_map() {
+        let pairs: Cow<[(&str, u32)]> = Cow::Borrowed(&[("a", 1), ("b", 2)]);
+        let map = CowIter::from_cow(pairs).collect_into_cow_map();
+
+        assert_eq!(map.len(), 2);
+        assert!(matches!(map[&Cow::Borrowed(&"a")], Cow::Borrowed(&1)));
+        assert!(matches!(map[&Cow::Borrowed(&"b")], Cow::Borrowed(&2)));
+
+        let pairs: Cow<[(&str, u32)]> = Cow::Owned(vec![("a", 1), ("b", 2)]);
+        let map = CowIter::from_cow(pairs).collect_into_cow_map();
+
+        assert!(map.keys().all(|key| matches!(key, Cow::Owned(_))));
+        assert!(matches!(map[&Cow::Owned("a")], Cow::Owned(1)));
+        assert!(matches!(map[&Cow::Owned("b")], Cow::Owned(2)));
+    }
+
+    #[test]
+    fn split_first_cow() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let (first, rest) = CowIter::from_cow(numbers).split_first_cow().unwrap();
+
+        assert_eq!(first, Cow::Borrowed(&1));
+        assert_eq!(
+            rest.collect::<Vec<_>>(),
+            [Cow::Borrowed(&2), Cow::Borrowed(&3)]
+        );
+
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let (first, rest) = CowIter::from_cow(numbers).split_first_cow().unwrap();
+
+        assert!(matches!(first, Cow::Owned(1)));
+        assert_eq!(rest.collect::<Vec<_>>(), [Cow::Owned(2), Cow::Owned(3)]);
+
+        let empty: Cow<[u32]> = Cow::Borrowed(&[]);
+        assert!(CowIter::from_cow(empty).split_first_cow().is_none());
+    }
+
+    #[test]
+    fn split_last_cow() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let (last, rest) = CowIter::from_cow(numbers).split_last_cow().unwrap();
+
+        assert_eq!(last, Cow::Borrowed(&3));
+        assert_eq!(
+            rest.collect::<Vec<_>>(),
+            [Cow::Borrowed(&1), Cow::Borrowed(&2)]
+        );
+
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let (last, rest) = CowIter::from_cow(numbers).split_last_cow().unwrap();
+
+        assert!(matches!(last, Cow::Owned(3)));
+        assert_eq!(rest.collect::<Vec<_>>(), [Cow::Owned(1), Cow::Owned(2)]);
+    }
+
+    #[test]
+    fn rotate_left_cow() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4]);
+        let rotated = CowIter::from_cow(numbers).rotate_left_cow(1);
+
+        assert!(matches!(rotated, Cow::Owned(_)));
+        assert_eq!(rotated.as_ref(), &[2, 3, 4, 1]);
+
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4]);
+        let rotated = CowIter::from_cow(numbers).rotate_left_cow(6);
+        assert_eq!(rotated.as_ref(), &[3, 4, 1, 2]);
+
+        let empty: Cow<[u32]> = Cow::Borrowed(&[]);
+        assert!(CowIter::from_cow(empty).rotate_left_cow(1).is_empty());
+    }
+
+    #[test]
+    fn interleave_cow_keeps_borrow_state() {
+        let odd: Cow<[u32]> = Cow::Borrowed(&[1, 3, 5]);
+        let even: Cow<[u32]> = Cow::Owned(vec![2, 4]);
+
+        let interleaved: Vec<_> = CowIter::from_cow(odd).interleave_cow(even).collect();
+
+        let values: Vec<u32> = interleaved.iter().map(|element| **element).collect();
+        assert_eq!(values, [1, 2, 3, 4, 5]);
+        assert!(matches!(interleaved[0], Cow::Borrowed(_)));
+        assert!(matches!(interleaved[1], Cow::Owned(_)));
+        assert!(matches!(interleaved[4], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn interleave_cow_longer_other() {
+        let short: Cow<[u32]> = Cow::Borrowed(&[2, 4]);
+        let long: Cow<[u32]> = Cow::Borrowed(&[1, 3, 5]);
+
+        let interleaved: Vec<u32> = CowIter::from_cow(short)
+            .interleave_cow(long)
+            .map(Cow::into_owned)
+            .collect();
+
+        assert_eq!(interleaved, [2, 1, 4, 3, 5]);
+    }
+
+    #[test]
+    fn position_max_min_cow() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[3, 1, 4, 1, 5]);
+
+        assert_eq!(
+            CowIter::from_cow(numbers.clone()).position_max_cow(),
+            Some(4)
+        );
+        assert_eq!(CowIter::from_cow(numbers).position_min_cow(), Some(1));
+    }
+
+    #[test]
+    fn position_max_min_cow_ties() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![5, 1, 5, 1]);
+
+        assert_eq!(
+            CowIter::from_cow(numbers.clone()).position_max_cow(),
+            Some(2)
+        );
+        assert_eq!(CowIter::from_cow(numbers).position_min_cow(), Some(1));
+
+        let empty: Cow<[u32]> = Cow::Borrowed(&[]);
+        assert_eq!(CowIter::from_cow(empty).position_max_cow(), None);
+    }
+
+    #[test]
+    fn replace_all_cow_zeros() {
+        let numbers = vec![0, 1, 0, 2];
+        let replaced = CowIter::replace_all_cow(Cow::Borrowed(&numbers), |x| *x == 0, -1);
+
+        assert!(matches!(replaced, Cow::Owned(v) if v == [-1, 1, -1, 2]));
+    }
+
+    #[test]
+    fn replace_all_cow_nothing_matches() {
+        let numbers = vec![1, 2, 3];
+        let replaced = CowIter::replace_all_cow(Cow::Borrowed(&numbers), |x| *x == 0, -1);
+
+        let replaced = match replaced {
+            Cow::Borrowed(replaced) => replaced,
+            Cow::Owned(_) => panic!("nothing was replaced"),
+        };
+        assert!(ptr::eq(replaced, &numbers));
+    }
+
+    #[test]
+    fn replace_all_cow_owned() {
+        let numbers = Cow::Owned(vec![1, 0, 3]);
+        let replaced = CowIter::replace_all_cow(numbers, |x| *x == 0, -1);
+
+        assert!(matches!(replaced, Cow::Owned(v) if v == [1, -1, 3]));
+    }
+
+    #[test]
+    fn batching_cow_pairs() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4, 5, 6]);
+        let pairs: Vec<_> = CowIter::from_cow(numbers)
+            .batching_cow(|it| match it.next() {
+                Some(first) => it.next().map(|second| (first, second)),
+                None => None,
+            })
+            .map(|(first, second)| (first.into_owned(), second.into_owned()))
+            .collect();
+
+        assert_eq!(pairs, [(1, 2), (3, 4), (5, 6)]);
+    }
+
+    #[test]
+    fn unique_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 1, 3, 2]);
+        let unique = CowIter::from_cow(numbers).unique_cow();
+
+        assert!(matches!(
+            unique.as_slice(),
+            [Cow::Borrowed(1), Cow::Borrowed(2), Cow::Borrowed(3)]
+        ));
+    }
+
+    #[test]
+    fn unique_cow_owned() {
+        let words: Cow<[&str]> = Cow::Owned(vec!["foo", "bar", "foo"]);
+        let unique = CowIter::from_cow(words).unique_cow();
+
+        assert_eq!(unique, [Cow::Owned::<&str>("foo"), Cow::Owned("bar")]);
+    }
+
+    #[test]
+    fn fuse_cow_any_owned() {
+        let borrowed: Cow<[u32]> = Cow::Borrowed(&[1, 2]);
+        let mut iter = CowIter::from_cow(borrowed).fuse_cow();
+        assert_eq!(iter.by_ref().count(), 2);
+        assert!(!iter.any_owned());
+        assert_eq!(iter.next(), None);
+
+        let owned: Cow<[u32]> = Cow::Owned(vec![1, 2]);
+        let mut iter = CowIter::from_cow(owned).fuse_cow();
+        assert!(!iter.any_owned());
+        assert_eq!(iter.by_ref().count(), 2);
+        assert!(iter.any_owned());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn total_len_strings() {
+        let words: Cow<[String]> =
+            Cow::Owned(vec!["foo".to_string(), "barbaz".to_string(), String::new()]);
+
+        assert_eq!(CowIter::from_cow(words).total_len(), 9);
+    }
+
+    #[test]
+    fn intersect_cow_keeps_left_state() {
+        let left: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4]);
+        let right: Cow<[u32]> = Cow::Owned(vec![4, 2, 5]);
+
+        let intersection = CowIter::from_cow(left).intersect_cow(right);
+
+        assert!(matches!(
+            intersection.as_slice(),
+            [Cow::Borrowed(2), Cow::Borrowed(4)]
+        ));
+    }
+
+    #[test]
+    fn difference_cow_keeps_left_state() {
+        let left: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 1]);
+        let right: Cow<[u32]> = Cow::Borrowed(&[2, 5]);
+
+        let difference = CowIter::from_cow(left).difference_cow(right);
+
+        assert!(matches!(
+            difference.as_slice(),
+            [Cow::Owned(1), Cow::Owned(3), Cow::Owned(1)]
+        ));
+    }
+
+    #[test]
+    fn array_chunks_cow_drops_tail() {
+        let data: Cow<[u8]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+        let mut chunks = CowIter::from_cow(data).array_chunks_cow::<2>();
+
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+
+        let chunk: [Cow<u8>; 2] = chunks.next().unwrap();
+        assert!(matches!(chunk, [Cow::Borrowed(1), Cow::Borrowed(2)]));
+
+        let chunk = chunks.next().unwrap();
+        assert!(matches!(chunk, [Cow::Borrowed(3), Cow::Borrowed(4)]));
+
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_chunks_cow_zero() {
+        let data: Cow<[u8]> = Cow::Borrowed(&[1, 2]);
+        let _ = CowIter::from_cow(data).array_chunks_cow::<0>();
+    }
+
+    #[test]
+    fn find_cow_long_string() {
+        let strings = ["a".to_string(), "abcd".to_string(), "abcde".to_string()];
+        let data: Cow<[String]> = Cow::Borrowed(&strings);
+
+        let found = CowIter::from_cow(data).find_cow(|s| s.len() > 3);
+
+        assert!(matches!(found, Some(Cow::Borrowed(s)) if s == "abcd"));
+    }
+
+    #[test]
+    fn sorted_cow_owned() {
+        let data: Cow<[u32]> = Cow::Owned(vec![3, 1, 2]);
+        let sorted = CowIter::from_cow(data).sorted_cow();
+
+        assert!(matches!(
+            sorted.as_slice(),
+            [Cow::Owned(1), Cow::Owned(2), Cow::Owned(3)]
+        ));
+    }
+
+    #[test]
+    fn rev_borrowed() {
+        let data: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let reversed = data.into_cow_iter().rev().collect::<Vec<_>>();
+
+        assert!(matches!(
+            reversed.as_slice(),
+            [Cow::Borrowed(3), Cow::Borrowed(2), Cow::Borrowed(1)]
+        ));
+    }
+
+    #[test]
+    fn rev_owned() {
+        let data: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let reversed = data.into_cow_iter().rev().collect::<Vec<_>>();
+
+        assert!(matches!(
+            reversed.as_slice(),
+            [Cow::Owned(3), Cow::Owned(2), Cow::Owned(1)]
+        ));
+    }
+
+    #[test]
+    fn next_back_meets_next() {
+        let data: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let mut iter = data.into_cow_iter();
+
+        assert_eq!(iter.next_back(), Some(Cow::Borrowed(&3)));
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+        assert_eq!(iter.next_back(), Some(Cow::Borrowed(&2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn min_max_cow_borrowed() {
+        let data = [3, 1, 4, 1, 5];
+        let (min, max) = CowIter::from_cow(Cow::Borrowed(&data[..]))
+            .min_max_cow()
+            .unwrap();
+
+        assert!(matches!((&min, &max), (Cow::Borrowed(1), Cow::Borrowed(5))));
+        // The first minimum is returned.
+        assert!(std::ptr::eq(min.as_ref(), &data[1]));
+        assert!(std::ptr::eq(max.as_ref(), &data[4]));
+    }
+
+    #[test]
+    fn min_max_cow_single_and_empty() {
+        let data: Cow<[u32]> = Cow::Owned(vec![7]);
+        let min_max = CowIter::from_cow(data).min_max_cow();
+        assert!(matches!(min_max, Some((Cow::Owned(7), Cow::Owned(7)))));
+
+        let data: Cow<[u32]> = Cow::Borrowed(&[]);
+        assert!(CowIter::from_cow(data).min_max_cow().is_none());
+    }
+
+    #[test]
+    fn hash_set_borrowed() {
+        use super::cow_map_iter::NoClone;
+
+        let data = vec![NoClone(1), NoClone(2)]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let data: Cow<HashSet<NoClone>> = Cow::Borrowed(&data);
+
+        let mut elements = data
+            .into_cow_iter()
+            .map(|e| match e {
+                Cow::Borrowed(e) => e.0,
+                Cow::Owned(_) => panic!("Element should be borrowed"),
+            })
+            .collect::<Vec<_>>();
+        elements.sort_unstable();
+        assert_eq!(elements, [1, 2]);
+    }
+
+    #[test]
+    fn hash_set_owned() {
+        let data = vec![1, 2].into_iter().collect::<HashSet<u32>>();
+        let data: Cow<HashSet<u32>> = Cow::Owned(data);
+
+        let mut elements = data.into_cow_iter().collect::<Vec<_>>();
+        elements.sort_unstable();
+        assert!(matches!(
+            elements.as_slice(),
+            [Cow::Owned(1), Cow::Owned(2)]
+        ));
+    }
+
+    #[test]
+    fn btree_set_borrowed() {
+        use super::cow_map_iter::NoClone;
+
+        let data = vec![NoClone(3), NoClone(1), NoClone(2)]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        let data: Cow<BTreeSet<NoClone>> = Cow::Borrowed(&data);
+
+        let forward = data.clone().into_cow_iter().collect::<Vec<_>>();
+        assert!(matches!(
+            forward.as_slice(),
+            [
+                Cow::Borrowed(NoClone(1)),
+                Cow::Borrowed(NoClone(2)),
+                Cow::Borrowed(NoClone(3))
+            ]
+        ));
+
+        let mut iter = data.into_cow_iter();
+        assert!(matches!(iter.next_back(), Some(Cow::Borrowed(NoClone(3)))));
+        assert!(matches!(iter.next(), Some(Cow::Borrowed(NoClone(1)))));
+    }
+
+    #[test]
+    fn btree_set_owned() {
+        let data = vec![3, 1, 2].into_iter().collect::<BTreeSet<u32>>();
+        let data: Cow<BTreeSet<u32>> = Cow::Owned(data);
+
+        let backward = data.into_cow_iter().rev().collect::<Vec<_>>();
+        assert!(matches!(
+            backward.as_slice(),
+            [Cow::Owned(3), Cow::Owned(2), Cow::Owned(1)]
+        ));
+    }
+
+    #[test]
+    fn vec_deque_borrowed() {
+        let mut data = VecDeque::new();
+        data.push_back(2);
+        data.push_back(3);
+        data.push_front(1);
+        let data: Cow<VecDeque<u32>> = Cow::Borrowed(&data);
+
+        let forward = data.clone().into_cow_iter().collect::<Vec<_>>();
+        assert!(matches!(
+            forward.as_slice(),
+            [Cow::Borrowed(1), Cow::Borrowed(2), Cow::Borrowed(3)]
+        ));
+
+        let backward = data.into_cow_iter().rev().collect::<Vec<_>>();
+        assert!(matches!(
+            backward.as_slice(),
+            [Cow::Borrowed(3), Cow::Borrowed(2), Cow::Borrowed(1)]
+        ));
+    }
+
+    #[test]
+    fn vec_deque_owned() {
+        let mut data = VecDeque::new();
+        data.push_back(2);
+        data.push_back(3);
+        data.push_front(1);
+        let data: Cow<VecDeque<u32>> = Cow::Owned(data);
+
+        let forward = data.clone().into_cow_iter().collect::<Vec<_>>();
+        assert_eq!(forward, [Cow::Owned(1), Cow::Owned(2), Cow::Owned(3)]);
+        assert!(forward.iter().all(|e| matches!(e, Cow::Owned(_))));
+
+        let mut iter = data.into_cow_iter();
+        assert_eq!(iter.next_back(), Some(Cow::Owned(3)));
+        assert_eq!(iter.next(), Some(Cow::Owned(1)));
+        assert_eq!(iter.next_back(), Some(Cow::Owned(2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn option_some_borrowed() {
+        let data = Some(String::from("a"));
+        let data: Cow<Option<String>> = Cow::Borrowed(&data);
+        let elements = data.into_cow_iter().collect::<Vec<_>>();
+
+        assert!(matches!(elements.as_slice(), [Cow::Borrowed(s)] if *s == "a"));
+    }
+
+    #[test]
+    fn option_some_owned() {
+        let data: Cow<Option<String>> = Cow::Owned(Some(String::from("a")));
+        let elements = data.into_cow_iter().collect::<Vec<_>>();
+
+        assert!(matches!(elements.as_slice(), [Cow::Owned(s)] if s == "a"));
+    }
+
+    #[test]
+    fn option_none() {
+        let data: Cow<Option<String>> = Cow::Borrowed(&None);
+        assert_eq!(data.into_cow_iter().next(), None);
+
+        let data: Cow<Option<String>> = Cow::Owned(None);
+        assert_eq!(data.into_cow_iter().next(), None);
+    }
+
+    #[test]
+    fn array_empty() {
+        let data: Cow<[u32; 0]> = Cow::Borrowed(&[]);
+        assert_eq!(data.into_cow_iter().next(), None);
+
+        let data: Cow<[u32; 0]> = Cow::Owned([]);
+        assert_eq!(data.into_cow_iter().next(), None);
+    }
+
+    #[test]
+    fn array_single() {
+        let data: Cow<[u32; 1]> = Cow::Borrowed(&[7]);
+        let elements = data.into_cow_iter().collect::<Vec<_>>();
+        assert!(matches!(elements.as_slice(), [Cow::Borrowed(7)]));
+
+        let data: Cow<[u32; 1]> = Cow::Owned([7]);
+        let elements = data.into_cow_iter().collect::<Vec<_>>();
+        assert!(matches!(elements.as_slice(), [Cow::Owned(7)]));
+    }
+
+    #[test]
+    fn array_larger() {
+        let data: Cow<[u32; 5]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+        let mut iter = data.into_cow_iter();
+        assert_eq!(iter.len(), 5);
+        assert!(iter.all(|element| matches!(element, Cow::Borrowed(_))));
+
+        let data: Cow<[u32; 5]> = Cow::Owned([1, 2, 3, 4, 5]);
+        let elements = data.into_cow_iter().rev().collect::<Vec<_>>();
+        assert!(matches!(
+            elements.as_slice(),
+            [
+                Cow::Owned(5),
+                Cow::Owned(4),
+                Cow::Owned(3),
+                Cow::Owned(2),
+                Cow::Owned(1)
+            ]
+        ));
+    }
+
+    #[test]
+    fn collect_result_cow_all_ok() {
+        let data: Cow<[Result<u32, u8>]> = Cow::Borrowed(&[Ok(1), Ok(2), Ok(3)]);
+        let values = CowIter::from_cow(data).collect_result_cow().unwrap();
+
+        assert!(matches!(
+            values.as_slice(),
+            [Cow::Borrowed(1), Cow::Borrowed(2), Cow::Borrowed(3)]
+        ));
+    }
+
+    #[test]
+    fn collect_result_cow_mixed() {
+        let data: Cow<[Result<u32, u8>]> = Cow::Borrowed(&[Ok(1), Err(2), Ok(3), Err(4)]);
+        assert_eq!(CowIter::from_cow(data).collect_result_cow(), Err(2));
+
+        let data: Cow<[Result<u32, u8>]> = Cow::Owned(vec![Ok(1), Err(2), Err(4)]);
+        assert_eq!(CowIter::from_cow(data).collect_result_cow(), Err(2));
+    }
+
+    #[test]
+    fn next_after_exhaustion() {
+        let data: Cow<[u32]> = Cow::Borrowed(&[1]);
+        let mut iter = CowIter::from_cow(data);
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+
+        let data: Cow<[u32]> = Cow::Owned(vec![1]);
+        let mut iter = CowIter::from_cow(data);
+        assert_eq!(iter.next(), Some(Cow::Owned(1)));
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn is_fused() {
+        fn assert_fused<It: FusedIterator>(it: It) -> It {
+            it
+        }
+
+        let data: Cow<[u32]> = Cow::Borrowed(&[1, 2]);
+        let iter = assert_fused(CowIter::from_cow(data));
+        assert_eq!(assert_fused(iter.fuse()).count(), 2);
+
+        let data: Cow<[u32]> = Cow::Owned(vec![1, 2]);
+        assert_eq!(assert_fused(CowIter::from_cow(data)).count(), 2);
+    }
+
+    #[test]
+    fn clone_partially_consumed() {
+        use super::cow_map_iter::NoClone;
+
+        let data: Cow<[NoClone]> = Cow::Borrowed(&[NoClone(1), NoClone(2), NoClone(3)]);
+        let mut iter = CowIter::from_cow(data);
+        iter.next();
+
+        let mut cloned = iter.clone();
+        assert!(matches!(cloned.next(), Some(Cow::Borrowed(NoClone(2)))));
+        assert!(matches!(iter.next(), Some(Cow::Borrowed(NoClone(2)))));
+        assert!(matches!(iter.next(), Some(Cow::Borrowed(NoClone(3)))));
+        assert!(iter.next().is_none());
+        assert!(matches!(cloned.next(), Some(Cow::Borrowed(NoClone(3)))));
+        assert!(cloned.next().is_none());
+    }
+
+    #[test]
+    fn join_cow_borrowed() {
+        let data = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let data: Cow<[String]> = Cow::Borrowed(&data);
+
+        assert_eq!(CowIter::from_cow(data).join_cow(", "), "a, b, c");
+    }
+
+    #[test]
+    fn join_cow_empty() {
+        let data: Cow<[String]> = Cow::Owned(Vec::new());
+
+        assert_eq!(CowIter::from_cow(data).join_cow(", "), "");
+    }
+
+    #[test]
+    fn collect_cow_all_borrowed() {
+        let source = vec![1, 2, 3];
+        let items = CowIter::from_cow(Cow::Borrowed(&source));
+
+        let collected = collect_cow(&source, items);
+        assert!(matches!(collected, Cow::Borrowed(s) if ptr::eq(s, &source)));
+    }
+
+    #[test]
+    fn collect_cow_all_owned() {
+        let source = vec![1, 2, 3];
+        let data: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let items = CowIter::from_cow(data);
+
+        assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v == [1, 2, 3]));
+    }
+
+    #[test]
+    fn collect_cow_mixed() {
+        let source = vec![1, 2, 3];
+        let items = vec![
+            Cow::Borrowed(&source[0]),
+            Cow::Owned(2),
+            Cow::Borrowed(&source[2]),
+        ];
+
+        assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v == [1, 2, 3]));
+    }
+
+    #[test]
+    fn collect_cow_not_the_whole_source() {
+        let source = vec![1, 2, 3];
+        let reversed = CowIter::from_cow(Cow::Borrowed(&source)).rev();
+        assert!(matches!(collect_cow(&source, reversed), Cow::Owned(v) if v == [3, 2, 1]));
+
+        let items = CowIter::from_cow(Cow::Borrowed(&source)).skip(1);
+        assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v == [2, 3]));
+
+        let other = vec![1, 2, 3];
+        let items = CowIter::from_cow(Cow::Borrowed(&other));
+        assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v == [1, 2, 3]));
+    }
+
+    #[test]
+    fn collect_cow_zero_sized() {
+        let source = vec![(), (), ()];
+
+        let items = CowIter::from_cow(Cow::Borrowed(&source));
+        assert!(matches!(collect_cow(&source, items), Cow::Borrowed(v) if ptr::eq(v, &source)));
+
+        let items = CowIter::from_cow(Cow::Borrowed(&source)).skip(1);
+        assert!(matches!(collect_cow(&source, items), Cow::Owned(v) if v.len() == 2));
+    }
+
+    #[test]
+    fn enumerate_from_cow_offset() {
+        let data: Cow<[u32]> = Cow::Borrowed(&[7, 8, 9]);
+        let iter = CowIter::from_cow(data).enumerate_from_cow(100);
+        assert_eq!(iter.len(), 3);
+
+        let indexed = iter.collect::<Vec<_>>();
+        assert_eq!(
+            indexed,
+            [
+                (100, Cow::Borrowed(&7)),
+                (101, Cow::Borrowed(&8)),
+                (102, Cow::Borrowed(&9))
+            ]
+        );
+    }
+
+    #[test]
+    fn tee_cow_borrowed() {
+        use super::cow_map_iter::NoClone;
+
+        let data: Cow<[NoClone]> = Cow::Borrowed(&[NoClone(1), NoClone(2)]);
+        let (first, second) = CowIter::from_cow(data).tee_cow();
+
+        for half in [first, second].iter_mut() {
+            assert!(matches!(half.next(), Some(Cow::Borrowed(NoClone(1)))));
+            assert!(matches!(half.next(), Some(Cow::Borrowed(NoClone(2)))));
+            assert!(half.next().is_none());
+        }
+    }
+
+    #[test]
+    fn tee_cow_owned() {
+        let data: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let (mut first, mut second) = CowIter::from_cow(data).tee_cow();
+
+        assert_eq!(first.next(), Some(Cow::Owned(1)));
+        assert_eq!(first.next(), Some(Cow::Owned(2)));
+        assert_eq!(second.len(), 3);
+        assert_eq!(second.next(), Some(Cow::Owned(1)));
+        assert_eq!(second.next(), Some(Cow::Owned(2)));
+        assert_eq!(second.next(), Some(Cow::Owned(3)));
+        assert_eq!(second.next(), None);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first.next(), Some(Cow::Owned(3)));
+        assert_eq!(first.next(), None);
+    }
+
+    #[test]
+    fn tee_cow_dropped_half() {
+        use super::cow_map_iter::NoClone;
+
+        let data: Cow<[NoClone]> = Cow::Owned(vec![NoClone(1), NoClone(2)]);
+        let (first, second) = CowIter::from_cow(data).tee_cow();
+        drop(second);
+
+        let elements = first.map(Cow::into_owned).collect::<Vec<_>>();
+        assert_eq!(elements, [NoClone(1), NoClone(2)]);
+    }
+
+    #[test]
+    fn try_for_each_cow_stops_on_zero() {
+        let check = |n: &u32| if *n == 0 { Err("zero") } else { Ok(()) };
+
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 0, 3]);
+        assert_eq!(
+            CowIter::from_cow(numbers).try_for_each_cow(check),
+            Err("zero")
+        );
+
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        assert_eq!(CowIter::from_cow(numbers).try_for_each_cow(check), Ok(()));
+    }
+
+    #[test]
+    fn cmp_cow() {
+        let left: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let right: Cow<[u32]> = Cow::Owned(vec![1, 2, 4]);
+
+        assert_eq!(
+            CowIter::from_cow(left.clone()).cmp_cow(right.clone()),
+            Ordering::Less
+        );
+        assert_eq!(
+            CowIter::from_cow(right).cmp_cow(left.clone()),
+            Ordering::Greater
+        );
+        assert_eq!(
+            CowIter::from_cow(left.clone()).cmp_cow(left),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn cmp_cow_prefix() {
+        let short: Cow<[u32]> = Cow::Borrowed(&[1, 2]);
+        let long: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+
+        assert_eq!(
+            CowIter::from_cow(short.clone()).cmp_cow(long.clone()),
+            Ordering::Less
+        );
+        assert_eq!(CowIter::from_cow(long).cmp_cow(short), Ordering::Greater);
+    }
+
+    #[test]
+    fn copied_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let copied: Vec<u32> = CowIter::from_cow(numbers).copied_cow().collect();
+
+        assert_eq!(copied, [1, 2, 3]);
+    }
+
+    #[test]
+    fn copied_cow_owned() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3]);
+        let copied: Vec<u32> = CowIter::from_cow(numbers).copied_cow().collect();
+
+        assert_eq!(copied, [1, 2, 3]);
+    }
+
+    #[test]
+    fn flatten_once_owned() {
+        let numbers: Cow<[_]> = Cow::Owned(vec![Some(1), None, Some(3)]);
+        let flattened: Vec<Cow<u32>> = CowIter::from_cow(numbers).flatten_once().collect();
+
+        assert!(matches!(
+            flattened.as_slice(),
+            [Cow::Owned(1), Cow::Owned(3)]
+        ));
+    }
+
+    #[test]
+    fn len_borrowed_slice() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let mut iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn len_owned_slice() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4]);
+        let mut iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+
+        iter.next_back();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn take_cow_len() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+        let mut iter = CowIter::from_cow(numbers).take_cow(2);
+
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(Cow::Borrowed(&1)));
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn skip_cow_len() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4, 5]);
+        let mut iter = CowIter::from_cow(numbers).skip_cow(2);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Cow::Owned(3)));
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn rev_map_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+        let iter = CowIter::from_cow(numbers).rev_map_cow(|x| *x * 2);
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), [6, 4, 2]);
+    }
+
+    #[test]
+    fn group_into_map_by_parity() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+        let groups = CowIter::from_cow(numbers).group_into_map(|x| x % 2 == 0);
+
+        assert_eq!(groups.len(), 2);
+        assert!(matches!(
+            groups[&true].as_slice(),
+            [Cow::Borrowed(2), Cow::Borrowed(4)]
+        ));
+        assert!(matches!(
+            groups[&false].as_slice(),
+            [Cow::Borrowed(1), Cow::Borrowed(3), Cow::Borrowed(5)]
+        ));
+    }
+
+    #[test]
+    fn partition_point_cow_borrowed() {
+        let numbers: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3, 4, 5]);
+        let iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.partition_point_cow(|x| *x < 3), 2);
+        assert_eq!(iter.partition_point_cow(|x| *x < 10), 5);
+        assert_eq!(iter.partition_point_cow(|_| false), 0);
+    }
+
+    #[test]
+    fn partition_point_cow_owned() {
+        let numbers: Cow<[u32]> = Cow::Owned(vec![1, 2, 3, 4, 5]);
+        let mut iter = CowIter::from_cow(numbers);
+
+        assert_eq!(iter.partition_point_cow(|x| *x < 3), 2);
+
+        // Only the remaining elements are taken into account.
+        iter.next();
+        assert_eq!(iter.partition_point_cow(|x| *x < 3), 1);
+    }
+
+    #[test]
+    fn map_while_cow_stops_at_first_none() {
+        let numbers: Cow<[i32]> = Cow::Borrowed(&[1, 2, -3, 4]);
+        let iter =
+            CowIter::from_cow(numbers).map_while_cow(
+                |x| {
+                    if *x >= 0 {
+                        Some(*x as u32)
+                    } else {
+                        None
+                    }
+                },
+            );
+
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn map_while_cow_is_fused() {
+        let numbers: Cow<[i32]> = Cow::Borrowed(&[1, -2, 3]);
+        let mut iter =
+            CowIter::from_cow(numbers).map_while_cow(|x| if *x >= 0 { Some(*x) } else { None });
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None);
     }
 }
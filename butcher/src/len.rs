@@ -0,0 +1,91 @@
+//! Allows to get the length of a collection, whether it is wrapped in a `Cow`
+//! or not.
+//!
+//! See the documentation for [`Len`] for more.
+//!
+//! [`Len`]: trait.Len.html
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+/// A collection whose number of elements is known without iterating over it.
+///
+/// This trait is used by the derive macro: single-field tuple structs whose
+/// butchered field implements `Len` get a `len` and an `is_empty` method. It
+/// is implemented for the standard collections, for `str`, and for `Cow`,
+/// references and boxes of such types.
+///
+/// # Example
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use butcher::len::Len;
+///
+/// let borrowed: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+/// let owned: Cow<[u32]> = Cow::Owned(Vec::new());
+///
+/// assert_eq!(Len::len(&borrowed), 3);
+/// assert!(Len::is_empty(&owned));
+/// ```
+pub trait Len {
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns whether there is no element.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_len {
+    ( $( impl< $( $generic:ident ),* > for $ty:ty ),* $(,)? ) => {
+        $(
+            impl< $( $generic ),* > Len for $ty {
+                fn len(&self) -> usize {
+                    <$ty>::len(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_len! {
+    impl<> for str,
+    impl<> for String,
+    impl<T> for [T],
+    impl<T> for Vec<T>,
+    impl<T> for VecDeque<T>,
+    impl<T> for LinkedList<T>,
+    impl<T> for BinaryHeap<T>,
+    impl<T> for HashSet<T>,
+    impl<T> for BTreeSet<T>,
+    impl<K, V> for HashMap<K, V>,
+    impl<K, V> for BTreeMap<K, V>,
+}
+
+impl<'a, T> Len for Cow<'a, T>
+where
+    T: Len + ToOwned + ?Sized,
+{
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+}
+
+impl<T> Len for &T
+where
+    T: Len + ?Sized,
+{
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+}
+
+impl<T> Len for Box<T>
+where
+    T: Len + ?Sized,
+{
+    fn len(&self) -> usize {
+        T::len(self)
+    }
+}
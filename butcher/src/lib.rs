@@ -5,8 +5,10 @@
 //! This crate provides the following functionalities for data wrapped in `Cow`:
 //!   - [destructuring/pattern matching over structs and enums](#destructuringpattern-matching),
 //!   - [iterating over collections](#iteration),
+//!   - [indexing slices](#indexing),
 //!   - [flattening nested `Cow`](#flattening),
-//!   - [removing an indirection level for data in `Cow`](#removing-an-indirection-level).
+//!   - [removing an indirection level for data in `Cow`](#removing-an-indirection-level),
+//!   - [visiting the fields of butchered structs](#visiting-fields).
 //!
 //! ## Destructuring/pattern matching
 //!
@@ -58,6 +60,20 @@
 //! The `Butcher` procedural macro can be derived for [structs][butcher-struct]
 //! and for [enums][butcher-enum].
 //!
+//! Unions are not supported, as their fields can't be safely butchered:
+//!
+//! ```compile_fail
+//! # // This is only a smoke test: it passes whatever the error is. The error
+//! # // message is checked by the `try_from::union` test of `butcher_proc_macro`.
+//! use butcher::Butcher;
+//!
+//! #[derive(Butcher, Clone, Copy)]
+//! union IntOrFloat {
+//!     i: u32,
+//!     f: f32,
+//! }
+//! ```
+//!
 //! [butcher-struct]: deriving_butcher_struct/index.html
 //! [butcher-enum]: deriving_butcher_enum/index.html
 //!
@@ -83,6 +99,13 @@
 //!
 //! [`CowIter`]: iterator/enum.CowIter.html
 //!
+//! ## Indexing
+//!
+//! The [`CowIndex`] trait allows to get an element or a subslice of a slice
+//! wrapped in a `Cow`. The result is borrowed only if the slice is borrowed.
+//!
+//! [`CowIndex`]: index/trait.CowIndex.html
+//!
 //! ## Flattening
 //!
 //! In some situations, the `Butcher` proc macro can generate tricky fields,
@@ -98,22 +121,324 @@
 //! transform a `Cow<String>` into `Cow<str>`.
 //!
 //! [`AsDerefCow`]: as_deref/trait.AsDerefCow.html
+//!
+//! The [`AsRefCow`] trait does the same for types which only implement
+//! `AsRef`.
+//!
+//! [`AsRefCow`]: as_ref/trait.AsRefCow.html
+//!
+//! ## Visiting fields
+//!
+//! The [`ButcherVisit`] trait allows to visit each field of a butchered
+//! struct, without knowing its concrete type. This can be used to write
+//! generic tooling, such as pretty-printers.
+//!
+//! [`ButcherVisit`]: visit/trait.ButcherVisit.html
 
 pub mod as_deref;
+pub mod as_ref;
 pub mod deriving_butcher_enum;
 pub mod deriving_butcher_struct;
 pub mod flatten;
+pub mod index;
+pub mod into_vec;
 pub mod iterator;
+pub mod len;
+pub mod maybe_owned;
 pub mod methods;
+pub mod visit;
 
 pub use butcher_proc_macro::*;
 
 use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
 
 pub trait Butcher<'cow>: ToOwned + 'cow {
     type Output: 'cow;
 
+    /// Butchers a `Cow<Self>`.
+    ///
+    /// Discarding the butchered output is almost always a bug, so a warning is
+    /// emitted when it is not used:
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(unused_must_use)]
+    ///
+    /// use butcher::Butcher;
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Butcher, Clone)]
+    /// struct Foo {
+    ///     bar: usize,
+    /// }
+    ///
+    /// Foo::butcher(Cow::Owned(Foo { bar: 42 }));
+    /// ```
+    ///
+    /// The same code compiles once the output is used, so the error above can
+    /// only come from the `unused_must_use` lint:
+    ///
+    /// ```rust
+    /// #![deny(unused_must_use)]
+    ///
+    /// use butcher::Butcher;
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Butcher, Clone)]
+    /// struct Foo {
+    ///     bar: usize,
+    /// }
+    ///
+    /// let _butchered = Foo::butcher(Cow::Owned(Foo { bar: 42 }));
+    /// ```
+    #[must_use]
     fn butcher(this: Cow<'cow, Self>) -> Self::Output;
 
+    /// Butchers anything which can be converted into a `Cow<Self>`.
+    ///
+    /// When `Butcher` is derived, this accepts an owned value, a reference or
+    /// a `Cow`:
+    ///
+    /// ```rust
+    /// use butcher::Butcher;
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Butcher, Clone)]
+    /// struct Foo {
+    ///     bar: usize,
+    /// }
+    ///
+    /// let foo = Foo { bar: 42 };
+    ///
+    /// let ButcheredFoo { bar } = Foo::butcher_into(&foo);
+    /// assert!(matches!(bar, Cow::Borrowed(&42)));
+    ///
+    /// let ButcheredFoo { bar } = Foo::butcher_into(Cow::Borrowed(&foo));
+    /// assert!(matches!(bar, Cow::Borrowed(&42)));
+    ///
+    /// let ButcheredFoo { bar } = Foo::butcher_into(foo);
+    /// assert!(matches!(bar, Cow::Owned(42)));
+    /// ```
+    #[must_use]
+    fn butcher_into(this: impl Into<Cow<'cow, Self>>) -> Self::Output {
+        Self::butcher(this.into())
+    }
+
     fn unbutcher(this: Self::Output) -> Self;
+
+    /// Unbutchers the data, returning an error if one of the fields can't be
+    /// recreated.
+    ///
+    /// Only the fields butchered with a [`ButcherTransform`] can fail, when
+    /// their transform overrides [`try_untransform`]. The derive macro also
+    /// generates a `TryFrom<ButcheredFoo>` implementation for `Foo`, which
+    /// calls this function.
+    ///
+    /// [`ButcherTransform`]: methods/trait.ButcherTransform.html
+    /// [`try_untransform`]: methods/trait.ButcherTransform.html#method.try_untransform
+    fn try_unbutcher(this: Self::Output) -> Result<Self, UnbutcherError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::unbutcher(this))
+    }
+
+    /// Unbutchers the data and wraps it in an owned `Cow`.
+    ///
+    /// This allows to get back a `Cow<Self>` once the butchered fields have
+    /// been modified:
+    ///
+    /// ```rust
+    /// use butcher::Butcher;
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(Butcher, Clone)]
+    /// struct Foo {
+    ///     name: String,
+    ///     #[butcher(copy)]
+    ///     size: usize,
+    /// }
+    ///
+    /// let foo = Foo { name: "foo".to_string(), size: 3 };
+    ///
+    /// let mut butchered = Foo::butcher(Cow::Borrowed(&foo));
+    /// butchered.name.to_mut().push_str("bar");
+    /// butchered.size = 6;
+    ///
+    /// let foobar = Foo::to_cow_owned(butchered);
+    /// assert!(matches!(foobar, Cow::Owned(_)));
+    /// assert_eq!(foobar.name, "foobar");
+    /// assert_eq!(foobar.size, 6);
+    /// ```
+    fn to_cow_owned(this: Self::Output) -> Cow<'cow, Self>
+    where
+        Self: Sized + ToOwned<Owned = Self>,
+    {
+        Cow::Owned(Self::unbutcher(this))
+    }
+}
+
+macro_rules! impl_butcher_as_deref {
+    ( $( $( #[$meta:meta] )* $owned:ty => $borrowed:ty ),* $(,)? ) => {
+        $(
+            $( #[$meta] )*
+            impl<'cow> Butcher<'cow> for $owned {
+                type Output = Cow<'cow, $borrowed>;
+
+                fn butcher(this: Cow<'cow, Self>) -> Self::Output {
+                    match this {
+                        Cow::Borrowed(b) => Cow::Borrowed(Deref::deref(b)),
+                        Cow::Owned(o) => Cow::Owned(o),
+                    }
+                }
+
+                fn unbutcher(this: Self::Output) -> Self {
+                    this.into_owned()
+                }
+            }
+        )*
+    };
+}
+
+impl_butcher_as_deref! {
+    /// Butchers a `String` into a `Cow<str>`.
+    ///
+    /// This behaves like the `as_deref` butchering method, so that generic code
+    /// bounded on `Butcher` can accept a `Cow<String>`.
+    String => str,
+    /// Butchers a `PathBuf` into a `Cow<Path>`.
+    ///
+    /// This behaves like the `as_deref` butchering method, so that generic code
+    /// bounded on `Butcher` can accept a `Cow<PathBuf>`.
+    PathBuf => Path,
+}
+
+/// Butchers the content of a `Box`, and boxes the result.
+///
+/// This allows to use the `rebutcher` method on boxed fields of recursive
+/// types, whose butchered form must be boxed as well.
+impl<'cow, T> Butcher<'cow> for Box<T>
+where
+    T: Butcher<'cow> + Clone + ToOwned<Owned = T>,
+{
+    type Output = Box<T::Output>;
+
+    fn butcher(this: Cow<'cow, Self>) -> Self::Output {
+        match this {
+            Cow::Borrowed(b) => Box::new(T::butcher(Cow::Borrowed(b.as_ref()))),
+            Cow::Owned(o) => Box::new(T::butcher(Cow::Owned(*o))),
+        }
+    }
+
+    fn unbutcher(this: Self::Output) -> Self {
+        Box::new(T::unbutcher(*this))
+    }
+
+    fn try_unbutcher(this: Self::Output) -> Result<Self, UnbutcherError> {
+        T::try_unbutcher(*this).map(Box::new)
+    }
+}
+
+/// The error returned when butchered data can't be converted back into its
+/// initial form.
+///
+/// It is returned by [`Butcher::try_unbutcher`], and by the `TryFrom`
+/// implementations generated by the derive macro.
+///
+/// [`Butcher::try_unbutcher`]: trait.Butcher.html#method.try_unbutcher
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnbutcherError {
+    message: Cow<'static, str>,
+}
+
+impl UnbutcherError {
+    /// Creates an error with a message describing why a field can't be
+    /// recreated.
+    pub fn new(message: impl Into<Cow<'static, str>>) -> UnbutcherError {
+        UnbutcherError {
+            message: message.into(),
+        }
+    }
+
+    /// Returns the message describing the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for UnbutcherError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to unbutcher: {}", self.message)
+    }
+}
+
+impl Error for UnbutcherError {}
+
+#[cfg(test)]
+mod string {
+    use super::*;
+
+    #[test]
+    fn butcher_borrowed() {
+        let input = String::from("foo");
+        let output = String::butcher(Cow::Borrowed(&input));
+
+        assert!(matches!(output, Cow::Borrowed("foo")));
+        assert_eq!(String::unbutcher(output), "foo");
+    }
+
+    #[test]
+    fn butcher_owned() {
+        let output = String::butcher(Cow::Owned(String::from("foo")));
+
+        assert!(matches!(&output, Cow::Owned(o) if o == "foo"));
+        assert_eq!(String::unbutcher(output), "foo");
+    }
+}
+
+#[cfg(test)]
+mod path_buf {
+    use super::*;
+
+    #[test]
+    fn butcher_borrowed() {
+        let input = PathBuf::from("/tmp/foo");
+        let output = PathBuf::butcher(Cow::Borrowed(&input));
+
+        assert!(matches!(output, Cow::Borrowed(p) if p == Path::new("/tmp/foo")));
+        assert_eq!(PathBuf::unbutcher(output), input);
+    }
+
+    #[test]
+    fn butcher_owned() {
+        let output = PathBuf::butcher(Cow::Owned(PathBuf::from("/tmp/foo")));
+
+        assert!(matches!(&output, Cow::Owned(p) if p == Path::new("/tmp/foo")));
+        assert_eq!(PathBuf::unbutcher(output), PathBuf::from("/tmp/foo"));
+    }
+}
+
+#[cfg(test)]
+mod boxed {
+    use super::*;
+
+    #[test]
+    fn butcher_borrowed() {
+        let input = Box::new(String::from("foo"));
+        let output = Box::<String>::butcher(Cow::Borrowed(&input));
+
+        assert!(matches!(*output, Cow::Borrowed("foo")));
+        assert_eq!(Box::<String>::unbutcher(output), input);
+    }
+
+    #[test]
+    fn butcher_owned() {
+        let output = Box::<String>::butcher(Cow::Owned(Box::new(String::from("foo"))));
+
+        assert!(matches!(&*output, Cow::Owned(o) if o == "foo"));
+        assert_eq!(*Box::<String>::unbutcher(output), "foo");
+    }
 }
@@ -0,0 +1,125 @@
+//! A clone-on-write smart pointer, which only requires `Clone` for the owned
+//! data.
+//!
+//! See the documentation for [`MaybeOwned`] for more.
+//!
+//! [`MaybeOwned`]: enum.MaybeOwned.html
+
+use std::fmt::{self, Debug};
+use std::ops::Deref;
+
+/// Either a reference to a `T`, or an owned `T`.
+///
+/// This behaves like a `Cow<T>`, but bounds on `T` only mention [`Clone`],
+/// not [`ToOwned`]. It is the output of the [`MaybeOwnedMethod`] butchering
+/// method.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::maybe_owned::MaybeOwned;
+///
+/// let value = 42;
+/// let mut borrowed = MaybeOwned::Borrowed(&value);
+/// assert!(borrowed.is_borrowed());
+///
+/// *borrowed.to_mut() += 1;
+/// assert!(borrowed.is_owned());
+/// assert_eq!(*borrowed, 43);
+/// assert_eq!(value, 42);
+/// ```
+///
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`ToOwned`]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
+/// [`MaybeOwnedMethod`]: ../methods/struct.MaybeOwnedMethod.html
+pub enum MaybeOwned<'a, T> {
+    /// Borrowed data.
+    Borrowed(&'a T),
+    /// Owned data.
+    Owned(T),
+}
+
+impl<'a, T> MaybeOwned<'a, T> {
+    /// Returns whether the data is borrowed.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, MaybeOwned::Borrowed(_))
+    }
+
+    /// Returns whether the data is owned.
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    /// Borrows the data for a shorter lifetime.
+    pub fn reborrow(&self) -> MaybeOwned<'_, T> {
+        MaybeOwned::Borrowed(self)
+    }
+
+    /// Returns the owned data, cloning it if it is borrowed.
+    pub fn into_owned(self) -> T
+    where
+        T: Clone,
+    {
+        match self {
+            MaybeOwned::Borrowed(b) => b.clone(),
+            MaybeOwned::Owned(o) => o,
+        }
+    }
+
+    /// Returns a mutable reference to the owned data, cloning it first if it
+    /// is borrowed.
+    pub fn to_mut(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        if let MaybeOwned::Borrowed(b) = *self {
+            *self = MaybeOwned::Owned(b.clone());
+        }
+
+        match self {
+            MaybeOwned::Owned(o) => o,
+            MaybeOwned::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'a, T> Deref for MaybeOwned<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            MaybeOwned::Borrowed(b) => b,
+            MaybeOwned::Owned(o) => o,
+        }
+    }
+}
+
+impl<'a, T> Clone for MaybeOwned<'a, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            MaybeOwned::Borrowed(b) => MaybeOwned::Borrowed(b),
+            MaybeOwned::Owned(o) => MaybeOwned::Owned(o.clone()),
+        }
+    }
+}
+
+impl<'a, T> Debug for MaybeOwned<'a, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<MaybeOwned<'b, U>> for MaybeOwned<'a, T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &MaybeOwned<'b, U>) -> bool {
+        T::eq(self, other)
+    }
+}
@@ -16,9 +16,14 @@
 //! [`ButcherField`]: trait.ButcherField.html
 
 use std::borrow::{Borrow, Cow};
+use std::cell::Cell;
+use std::marker::PhantomData;
 use std::ops::Deref;
+use std::rc;
+use std::sync::{self, atomic};
 
-use crate::Butcher;
+use crate::maybe_owned::MaybeOwned;
+use crate::{Butcher, UnbutcherError};
 
 /// Allow to unify the behavior of the different butchering methods.
 ///
@@ -42,6 +47,14 @@ where
     ///
     /// This function will clone `i` if it contains borrowed data.
     fn unbutcher(i: Self::Output) -> T;
+
+    /// Creates back the initial input data, returning an error if it can't
+    /// be recreated.
+    ///
+    /// By default, this calls `unbutcher`, which never fails.
+    fn try_unbutcher(i: Self::Output) -> Result<T, UnbutcherError> {
+        Ok(Self::unbutcher(i))
+    }
 }
 
 /// The regular method, used by default.
@@ -155,6 +168,42 @@ where
     }
 }
 
+/// The vec_cow method.
+///
+/// This method transforms a [`Vec<T>`] into a `Vec<Cow<T>>`. Each element is
+/// borrowed if the struct is borrowed, and moved if the struct is owned.
+///
+/// This differs from [`AsDeref`], which returns a `Cow<[T]>`: the elements can
+/// be processed independently, without cloning the whole vector.
+///
+/// It requires `T` to implement [`Clone`].
+///
+/// [`Vec<T>`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`AsDeref`]: struct.AsDeref.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+pub struct FlattenVec;
+
+impl<'cow, T> ButcheringMethod<'cow, Vec<T>> for FlattenVec
+where
+    T: Clone + 'cow,
+{
+    type Output = Vec<Cow<'cow, T>>;
+
+    /// Moves each element in an `Owned` variant.
+    fn from_owned(i: Vec<T>) -> Self::Output {
+        i.into_iter().map(Cow::Owned).collect()
+    }
+
+    /// Borrows each element in a `Borrowed` variant.
+    fn from_borrowed(i: &'cow Vec<T>) -> Self::Output {
+        i.iter().map(Cow::Borrowed).collect()
+    }
+
+    fn unbutcher(i: Self::Output) -> Vec<T> {
+        i.into_iter().map(Cow::into_owned).collect()
+    }
+}
+
 /// The copy method.
 ///
 /// **Note**: this is not related to the `Copy` trait, but it effectively copies
@@ -243,6 +292,828 @@ where
     fn unbutcher(i: Self::Output) -> T {
         Butcher::unbutcher(i)
     }
+
+    fn try_unbutcher(i: Self::Output) -> Result<T, UnbutcherError> {
+        Butcher::try_unbutcher(i)
+    }
+}
+
+/// The into owned method.
+///
+/// This method transforms a field of type `Cow<T>` into the owned form of `T`,
+/// whether the initial data was borrowed or not. This can be usefull right
+/// before serialization, or when an owned-only pipeline is built.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// #[derive(Butcher, Clone)]
+/// struct Message<'a> {
+///     #[butcher(into_owned)]
+///     content: Cow<'a, str>,
+/// }
+///
+/// let input = Message { content: Cow::Borrowed("Hello, world!") };
+///
+/// let ButcheredMessage { content } = Message::butcher(Cow::Borrowed(&input));
+///
+/// let content: String = content;
+/// assert_eq!(content, "Hello, world!");
+/// ```
+///
+/// It requires the field to be a [`Cow`]. The inner data is cloned if it is
+/// borrowed.
+///
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+pub struct IntoOwned;
+
+impl<'cow, 'a, T> ButcheringMethod<'cow, Cow<'a, T>> for IntoOwned
+where
+    'a: 'cow,
+    T: ToOwned + ?Sized + 'a,
+    <T as ToOwned>::Owned: 'cow,
+{
+    type Output = <T as ToOwned>::Owned;
+
+    /// Returns the owned data, cloning it only if it is borrowed.
+    fn from_owned(i: Cow<'a, T>) -> Self::Output {
+        i.into_owned()
+    }
+
+    /// Clones the data.
+    fn from_borrowed(i: &'cow Cow<'a, T>) -> Self::Output {
+        i.as_ref().to_owned()
+    }
+
+    fn unbutcher(i: Self::Output) -> Cow<'a, T> {
+        Cow::Owned(i)
+    }
+}
+
+/// The skip method.
+///
+/// This method discards the field: the corresponding butchered field has type
+/// `()`. It can be used for fields which can't be cloned, such as trait
+/// objects.
+///
+/// When unbutchering, the field is recreated by `D`, which implements
+/// [`SkipDefault`]. By default, the [`Default`] implementation of the field is
+/// used. Fields which don't implement [`Default`], such as
+/// `Box<dyn Fn(u32) -> u32>`, can use `#[butcher(skip(default = Type))]`
+/// instead.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+/// [`SkipDefault`]: trait.SkipDefault.html
+pub struct Skip<D = DefaultValue>(PhantomData<D>);
+
+impl<'cow, T, D> ButcheringMethod<'cow, T> for Skip<D>
+where
+    T: 'cow,
+    D: SkipDefault<T>,
+{
+    type Output = ();
+
+    /// Drops the data.
+    fn from_owned(_: T) -> Self::Output {}
+
+    /// Ignores the data.
+    fn from_borrowed(_: &'cow T) -> Self::Output {}
+
+    /// Creates a default value.
+    fn unbutcher(_: Self::Output) -> T {
+        D::default_value()
+    }
+}
+
+/// Creates the value of a field butchered with the [`Skip`] method when it is
+/// unbutchered.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::methods::SkipDefault;
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// struct Identity;
+///
+/// impl SkipDefault<Box<dyn Fn(u32) -> u32>> for Identity {
+///     fn default_value() -> Box<dyn Fn(u32) -> u32> {
+///         Box::new(|x| x)
+///     }
+/// }
+///
+/// #[derive(Butcher)]
+/// struct Job {
+///     #[butcher(copy)]
+///     id: u32,
+///     #[butcher(skip(default = Identity))]
+///     callback: Box<dyn Fn(u32) -> u32>,
+/// }
+///
+/// impl Clone for Job {
+///     // The callback can't be cloned, so it is reset.
+///     fn clone(&self) -> Job {
+///         Job { id: self.id, callback: Identity::default_value() }
+///     }
+/// }
+///
+/// let job = Job { id: 1, callback: Box::new(|x| x + 1) };
+/// let ButcheredJob { id, callback: () } = Job::butcher(Cow::Borrowed(&job));
+/// assert_eq!(id, 1);
+///
+/// let job = Job::unbutcher(Job::butcher(Cow::Owned(job)));
+/// assert_eq!((job.callback)(42), 42);
+/// ```
+///
+/// [`Skip`]: struct.Skip.html
+pub trait SkipDefault<T> {
+    /// Creates the value of the field.
+    fn default_value() -> T;
+}
+
+/// Creates the value of a skipped field with its [`Default`] implementation.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+pub struct DefaultValue;
+
+impl<T> SkipDefault<T> for DefaultValue
+where
+    T: Default,
+{
+    fn default_value() -> T {
+        T::default()
+    }
+}
+
+/// The weak method.
+///
+/// This method is used on weak references, such as [`rc::Weak<T>`] or
+/// [`sync::Weak<T>`], which implement [`WeakReference`]. It upgrades the
+/// weak reference, and returns the strong pointer if the pointed value is
+/// still alive, or `None` otherwise.
+///
+/// The pointed value is never cloned. Unbutchering downgrades the strong
+/// pointer back, or creates a weak reference which does not point to anything
+/// if the value was already dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+/// use std::rc::{Rc, Weak};
+///
+/// #[derive(Butcher, Clone)]
+/// struct Node {
+///     #[butcher(copy)]
+///     value: u32,
+///     #[butcher(weak)]
+///     parent: Weak<u32>,
+/// }
+///
+/// let parent = Rc::new(42);
+/// let node = Node { value: 1, parent: Rc::downgrade(&parent) };
+///
+/// let ButcheredNode { parent: upgraded, .. } = Node::butcher(Cow::Borrowed(&node));
+/// assert!(Rc::ptr_eq(&upgraded.unwrap(), &parent));
+///
+/// drop(parent);
+///
+/// let ButcheredNode { parent, .. } = Node::butcher(Cow::Borrowed(&node));
+/// assert_eq!(parent, None);
+/// # // Atomic weak references and generic pointed types must be supported.
+/// # #[derive(Butcher, Clone)]
+/// # struct Shared<T> {
+/// #     #[butcher(weak)]
+/// #     parent: std::sync::Weak<T>,
+/// # }
+/// # let parent = std::sync::Arc::new(String::from("foo"));
+/// # let shared = Shared { parent: std::sync::Arc::downgrade(&parent) };
+/// # let butchered = Shared::butcher(Cow::Owned(shared));
+/// # assert!(std::sync::Arc::ptr_eq(butchered.reborrow().parent.as_ref().unwrap(), &parent));
+/// # // The reference must be kept when unbutchering.
+/// # let shared = Shared::unbutcher(butchered);
+/// # assert!(std::sync::Arc::ptr_eq(&shared.parent.upgrade().unwrap(), &parent));
+/// ```
+///
+/// [`rc::Weak<T>`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`sync::Weak<T>`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+/// [`WeakReference`]: trait.WeakReference.html
+pub struct Weak;
+
+impl<'cow, W> ButcheringMethod<'cow, W> for Weak
+where
+    W: WeakReference + 'cow,
+{
+    type Output = Option<W::Strong>;
+
+    /// Upgrades the weak reference.
+    fn from_owned(i: W) -> Self::Output {
+        i.upgrade_strong()
+    }
+
+    /// Upgrades the weak reference.
+    fn from_borrowed(i: &'cow W) -> Self::Output {
+        i.upgrade_strong()
+    }
+
+    /// Downgrades the strong pointer, or creates a weak reference which does
+    /// not point to anything.
+    fn unbutcher(i: Self::Output) -> W {
+        match i {
+            Some(strong) => W::downgrade(&strong),
+            None => W::dangling(),
+        }
+    }
+}
+
+/// A weak reference, which can be butchered with the [`Weak`] method.
+///
+/// It is implemented for [`rc::Weak<T>`] and [`sync::Weak<T>`].
+///
+/// [`Weak`]: struct.Weak.html
+/// [`rc::Weak<T>`]: https://doc.rust-lang.org/std/rc/struct.Weak.html
+/// [`sync::Weak<T>`]: https://doc.rust-lang.org/std/sync/struct.Weak.html
+pub trait WeakReference {
+    /// The strong pointer obtained by upgrading the weak reference.
+    type Strong: Clone;
+
+    /// Upgrades the weak reference, if the pointed value is still alive.
+    fn upgrade_strong(&self) -> Option<Self::Strong>;
+
+    /// Creates a weak reference to the value pointed by `strong`.
+    fn downgrade(strong: &Self::Strong) -> Self;
+
+    /// Creates a weak reference which does not point to anything.
+    fn dangling() -> Self;
+}
+
+macro_rules! impl_weak_reference {
+    ($( $weak:ty => $strong:ty ),* $(,)?) => {
+        $(
+            impl<T> WeakReference for $weak {
+                type Strong = $strong;
+
+                fn upgrade_strong(&self) -> Option<$strong> {
+                    <$weak>::upgrade(self)
+                }
+
+                fn downgrade(strong: &$strong) -> Self {
+                    <$strong>::downgrade(strong)
+                }
+
+                fn dangling() -> Self {
+                    <$weak>::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_weak_reference! {
+    rc::Weak<T> => rc::Rc<T>,
+    sync::Weak<T> => sync::Arc<T>,
+}
+
+/// The option ref method.
+///
+/// This method is used on optional references, such as `Option<&'a T>`. It
+/// returns an `Option<Cow<'cow, T>>`, which borrows through the reference
+/// whether the struct is owned or borrowed. It requires `T` to implement
+/// [`Clone`]. The reference must live for `'cow`, which is usually inferred
+/// thanks to the variance of the struct.
+///
+/// An `Owned` variant, which can only be set by hand on the butchered struct,
+/// can't be turned back into a reference. In this case, [`try_unbutcher`]
+/// returns an error and `unbutcher` panics.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// #[derive(Butcher, Clone)]
+/// struct Node<'a> {
+///     #[butcher(copy)]
+///     value: u32,
+///     #[butcher(option_ref)]
+///     parent: Option<&'a Node<'a>>,
+/// }
+///
+/// let root = Node { value: 1, parent: None };
+/// let child = Node { value: 2, parent: Some(&root) };
+///
+/// let ButcheredNode { parent, .. } = Node::butcher(Cow::Borrowed(&child));
+/// assert!(matches!(parent, Some(Cow::Borrowed(node)) if node.value == 1));
+///
+/// let ButcheredNode { parent, .. } = Node::butcher(Cow::Owned(child));
+/// assert!(matches!(parent, Some(Cow::Borrowed(node)) if std::ptr::eq(node, &root)));
+///
+/// let ButcheredNode { parent, .. } = Node::butcher(Cow::Borrowed(&root));
+/// assert!(parent.is_none());
+/// # // The references must be kept when unbutchering.
+/// # let butchered = Node::butcher(Cow::Owned(Node { value: 3, parent: Some(&root) }));
+/// # let node = Node::unbutcher(butchered);
+/// # assert!(std::ptr::eq(node.parent.unwrap(), &root));
+/// ```
+///
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`try_unbutcher`]: trait.ButcheringMethod.html#method.try_unbutcher
+pub struct OptionRef;
+
+// The reference is turned back into an `Option<&'a T>` when unbutchering, so
+// `'a` and `'cow` must be the same lifetime.
+impl<'cow, 'a, T> ButcheringMethod<'cow, Option<&'a T>> for OptionRef
+where
+    'a: 'cow,
+    'cow: 'a,
+    T: Clone + 'a,
+{
+    type Output = Option<Cow<'cow, T>>;
+
+    /// Borrows the pointed value.
+    fn from_owned(i: Option<&'a T>) -> Self::Output {
+        i.map(Cow::Borrowed)
+    }
+
+    /// Borrows the pointed value.
+    fn from_borrowed(i: &'cow Option<&'a T>) -> Self::Output {
+        i.map(Cow::Borrowed)
+    }
+
+    /// Recreates the reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is `Owned`.
+    fn unbutcher(i: Self::Output) -> Option<&'a T> {
+        match Self::try_unbutcher(i) {
+            Ok(reference) => reference,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Recreates the reference, or returns an error if the value is `Owned`.
+    fn try_unbutcher(i: Self::Output) -> Result<Option<&'a T>, UnbutcherError> {
+        i.map(|pointed| match pointed {
+            Cow::Borrowed(b) => Ok(b),
+            Cow::Owned(_) => Err(UnbutcherError::new(
+                "an owned value can't be turned back into a reference",
+            )),
+        })
+        .transpose()
+    }
+}
+
+/// The maybe owned method.
+///
+/// This method transforms a `T` into a [`MaybeOwned<T>`], which borrows the
+/// field when the struct is borrowed, and owns it otherwise. Unlike the
+/// [`Regular`] method, the output type only requires `T` to implement
+/// [`Clone`], and does not involve [`ToOwned`].
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::maybe_owned::MaybeOwned;
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// #[derive(Butcher, Clone)]
+/// struct Config {
+///     #[butcher(maybe_owned)]
+///     retries: Vec<u32>,
+/// }
+///
+/// let config = Config { retries: vec![1, 2, 4] };
+///
+/// let ButcheredConfig { retries } = Config::butcher(Cow::Borrowed(&config));
+/// assert!(matches!(retries, MaybeOwned::Borrowed(r) if std::ptr::eq(r, &config.retries)));
+///
+/// let ButcheredConfig { mut retries } = Config::butcher(Cow::Owned(config.clone()));
+/// assert!(retries.is_owned());
+/// retries.to_mut().push(8);
+///
+/// let config = Config::unbutcher(ButcheredConfig { retries });
+/// assert_eq!(config.retries, [1, 2, 4, 8]);
+/// ```
+///
+/// [`MaybeOwned<T>`]: ../maybe_owned/enum.MaybeOwned.html
+/// [`Regular`]: struct.Regular.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`ToOwned`]: https://doc.rust-lang.org/std/borrow/trait.ToOwned.html
+pub struct MaybeOwnedMethod;
+
+impl<'cow, T> ButcheringMethod<'cow, T> for MaybeOwnedMethod
+where
+    T: Clone + 'cow,
+{
+    type Output = MaybeOwned<'cow, T>;
+
+    /// Creates an `Owned` variant, containing `T`.
+    fn from_owned(i: T) -> Self::Output {
+        MaybeOwned::Owned(i)
+    }
+
+    /// Creates a `Borrowed` variant, containing a reference to `T`.
+    fn from_borrowed(i: &'cow T) -> Self::Output {
+        MaybeOwned::Borrowed(i)
+    }
+
+    /// Recreates the original data, cloning it if it is borrowed.
+    fn unbutcher(i: Self::Output) -> T {
+        i.into_owned()
+    }
+}
+
+/// An optional reference, which can be butchered with the [`OptionRef`]
+/// method.
+///
+/// It is implemented for `Option<&T>` when `T` implements [`Clone`].
+///
+/// [`OptionRef`]: struct.OptionRef.html
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+pub trait OptionalReference {
+    /// The type of the pointed value.
+    type Pointed: Clone;
+}
+
+impl<T> OptionalReference for Option<&T>
+where
+    T: Clone,
+{
+    type Pointed = T;
+}
+
+/// The snapshot method.
+///
+/// This method is used on interior-mutable fields, such as [`Cell`] or the
+/// atomic integers. It does not output any [`Cow`] at all. Instead, it reads
+/// the value contained in the field.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// #[derive(Butcher)]
+/// struct Counter {
+///     #[butcher(snapshot)]
+///     hits: AtomicU32,
+/// }
+///
+/// // AtomicU32 is not Clone, so Clone must be implemented manually.
+/// impl Clone for Counter {
+///     fn clone(&self) -> Counter {
+///         Counter {
+///             hits: AtomicU32::new(self.hits.load(Ordering::SeqCst)),
+///         }
+///     }
+/// }
+///
+/// let input = Counter { hits: AtomicU32::new(42) };
+///
+/// let ButcheredCounter { hits } = Counter::butcher(Cow::Borrowed(&input));
+///
+/// let hits: u32 = hits;
+/// assert_eq!(hits, 42);
+/// ```
+///
+/// It requires the field type to implement [`SnapshotValue`].
+///
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+/// [`SnapshotValue`]: trait.SnapshotValue.html
+pub struct Snapshot;
+
+impl<'cow, T> ButcheringMethod<'cow, T> for Snapshot
+where
+    T: SnapshotValue + 'cow,
+    <T as SnapshotValue>::Value: 'cow,
+{
+    type Output = <T as SnapshotValue>::Value;
+
+    /// Moves the value out of the wrapper.
+    fn from_owned(i: T) -> Self::Output {
+        i.into_value()
+    }
+
+    /// Reads the value contained in the wrapper.
+    fn from_borrowed(i: &'cow T) -> Self::Output {
+        i.snapshot()
+    }
+
+    fn unbutcher(i: Self::Output) -> T {
+        T::from_value(i)
+    }
+}
+
+/// An interior-mutable wrapper, whose content can be read by the [`Snapshot`]
+/// butchering method.
+///
+/// This trait is implemented for [`Cell`], when its content is `Copy`, and for
+/// the atomic types. The atomic types are read with the [`SeqCst`] ordering.
+///
+/// [`Snapshot`]: struct.Snapshot.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`SeqCst`]: https://doc.rust-lang.org/std/sync/atomic/enum.Ordering.html#variant.SeqCst
+pub trait SnapshotValue {
+    /// The type of the value contained in the wrapper.
+    type Value: std::marker::Copy;
+
+    /// Reads the current value.
+    fn snapshot(&self) -> Self::Value;
+
+    /// Consumes the wrapper, returning the contained value.
+    fn into_value(self) -> Self::Value;
+
+    /// Creates a new wrapper containing `value`.
+    fn from_value(value: Self::Value) -> Self;
+}
+
+impl<T> SnapshotValue for Cell<T>
+where
+    T: std::marker::Copy,
+{
+    type Value = T;
+
+    fn snapshot(&self) -> T {
+        self.get()
+    }
+
+    fn into_value(self) -> T {
+        self.into_inner()
+    }
+
+    fn from_value(value: T) -> Cell<T> {
+        Cell::new(value)
+    }
+}
+
+macro_rules! impl_snapshot_value_for_atomic {
+    ($( $atomic:ident => $value:ty ),* $(,)?) => {
+        $(
+            impl SnapshotValue for atomic::$atomic {
+                type Value = $value;
+
+                fn snapshot(&self) -> $value {
+                    self.load(atomic::Ordering::SeqCst)
+                }
+
+                fn into_value(self) -> $value {
+                    self.into_inner()
+                }
+
+                fn from_value(value: $value) -> atomic::$atomic {
+                    atomic::$atomic::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_snapshot_value_for_atomic! {
+    AtomicBool => bool,
+    AtomicI8 => i8,
+    AtomicI16 => i16,
+    AtomicI32 => i32,
+    AtomicI64 => i64,
+    AtomicIsize => isize,
+    AtomicU8 => u8,
+    AtomicU16 => u16,
+    AtomicU32 => u32,
+    AtomicU64 => u64,
+    AtomicUsize => usize,
+}
+
+/// The chain method.
+///
+/// This method applies two butchering methods one after the other. The
+/// `First` method must return a `Cow<Mid>`, and the `Then` method is applied
+/// on its content. The owned data is moved from one method to the other, and
+/// the borrowed data stays borrowed.
+///
+/// It is used when multiple butchering methods are specified on a field. They
+/// are applied from left to right.
+///
+/// # Example
+///
+/// In the following code, a `Box<String>` is unboxed, and then dereferenced
+/// to a `str`:
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// #[derive(Butcher, Clone)]
+/// struct Foo {
+///     #[butcher(unbox, as_deref)]
+///     bar: Box<String>,
+/// }
+///
+/// let input = Foo { bar: Box::new(String::from("Hello")) };
+///
+/// let ButcheredFoo { bar } = Foo::butcher(Cow::Borrowed(&input));
+///
+/// let bar: Cow<str> = bar;
+/// assert_eq!(bar, Cow::Borrowed("Hello"));
+/// ```
+///
+/// It requires `Mid` to implement [`Clone`].
+///
+/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+pub struct Chain<First, Then, Mid>(PhantomData<(First, Then, Mid)>);
+
+impl<'cow, T, First, Then, Mid> ButcheringMethod<'cow, T> for Chain<First, Then, Mid>
+where
+    T: 'cow,
+    Mid: Clone + 'cow,
+    First: ButcheringMethod<'cow, T, Output = Cow<'cow, Mid>>,
+    Then: ButcheringMethod<'cow, Mid>,
+{
+    type Output = <Then as ButcheringMethod<'cow, Mid>>::Output;
+
+    /// Applies `Then` on the output of `First`.
+    fn from_owned(i: T) -> Self::Output {
+        match First::from_owned(i) {
+            Cow::Owned(o) => Then::from_owned(o),
+            Cow::Borrowed(b) => Then::from_borrowed(b),
+        }
+    }
+
+    /// Applies `Then` on the output of `First`.
+    fn from_borrowed(i: &'cow T) -> Self::Output {
+        match First::from_borrowed(i) {
+            Cow::Owned(o) => Then::from_owned(o),
+            Cow::Borrowed(b) => Then::from_borrowed(b),
+        }
+    }
+
+    fn unbutcher(i: Self::Output) -> T {
+        First::unbutcher(Cow::Owned(Then::unbutcher(i)))
+    }
+
+    fn try_unbutcher(i: Self::Output) -> Result<T, UnbutcherError> {
+        First::try_unbutcher(Cow::Owned(Then::try_unbutcher(i)?))
+    }
+}
+
+/// The unnest method.
+///
+/// This method transforms a field of type `Cow<'a, T>` into a `Cow<'cow, T>`,
+/// removing the nested `Cow` which would be created by the [`Regular`] method.
+/// The output is owned only if both the input and the field are owned.
+///
+/// It can be used when [`ButcherField`] is implemented by hand for types
+/// containing a `Cow`.
+///
+/// [`Regular`]: struct.Regular.html
+/// [`ButcherField`]: trait.ButcherField.html
+pub struct UnnestMethod;
+
+impl<'cow, 'a, T> ButcheringMethod<'cow, Cow<'a, T>> for UnnestMethod
+where
+    'a: 'cow,
+    T: ToOwned + ?Sized + 'a,
+    <T as ToOwned>::Owned: 'cow,
+{
+    type Output = Cow<'cow, T>;
+
+    /// Returns the field, whose lifetime is shortened.
+    fn from_owned(i: Cow<'a, T>) -> Self::Output {
+        i
+    }
+
+    /// Borrows the content of the field.
+    fn from_borrowed(i: &'cow Cow<'a, T>) -> Self::Output {
+        Cow::Borrowed(i.as_ref())
+    }
+
+    /// Recreates the field. Borrowed data is cloned, as it can't be borrowed
+    /// for `'a`.
+    fn unbutcher(i: Self::Output) -> Cow<'a, T> {
+        Cow::Owned(i.into_owned())
+    }
+}
+
+/// The map method.
+///
+/// This method applies a user-defined transform on the field. The transform
+/// is specified with the `transform` attribute, for instance
+/// `#[butcher(transform = Uppercase)]`, and must implement
+/// [`ButcherTransform`].
+///
+/// [`ButcherTransform`]: trait.ButcherTransform.html
+pub struct Map<Tr>(PhantomData<Tr>);
+
+impl<'cow, T, Tr> ButcheringMethod<'cow, T> for Map<Tr>
+where
+    T: 'cow,
+    Tr: ButcherTransform<'cow, T>,
+{
+    type Output = <Tr as ButcherTransform<'cow, T>>::Output;
+
+    fn from_owned(i: T) -> Self::Output {
+        Tr::transform_owned(i)
+    }
+
+    fn from_borrowed(i: &'cow T) -> Self::Output {
+        Tr::transform_borrowed(i)
+    }
+
+    fn unbutcher(i: Self::Output) -> T {
+        Tr::untransform(i)
+    }
+
+    fn try_unbutcher(i: Self::Output) -> Result<T, UnbutcherError> {
+        Tr::try_untransform(i)
+    }
+}
+
+/// A user-defined transform, which can be applied on a field with the
+/// [`Map`] method.
+///
+/// # Example
+///
+/// The following transform converts a `String` to uppercase. It borrows the
+/// initial data when it is already in uppercase:
+///
+/// ```rust
+/// use butcher::methods::ButcherTransform;
+/// use butcher::Butcher;
+/// use std::borrow::Cow;
+///
+/// struct Uppercase;
+///
+/// impl<'cow> ButcherTransform<'cow, String> for Uppercase {
+///     type Output = Cow<'cow, str>;
+///
+///     fn transform_owned(i: String) -> Self::Output {
+///         Cow::Owned(i.to_uppercase())
+///     }
+///
+///     fn transform_borrowed(i: &'cow String) -> Self::Output {
+///         if i.chars().any(char::is_lowercase) {
+///             Cow::Owned(i.to_uppercase())
+///         } else {
+///             Cow::Borrowed(i)
+///         }
+///     }
+///
+///     fn untransform(i: Self::Output) -> String {
+///         i.into_owned()
+///     }
+/// }
+///
+/// #[derive(Butcher, Clone)]
+/// struct Greeting {
+///     #[butcher(transform = Uppercase)]
+///     name: String,
+/// }
+///
+/// let greeting = Greeting { name: String::from("Ada") };
+/// let ButcheredGreeting { name } = Greeting::butcher(Cow::Borrowed(&greeting));
+/// assert_eq!(name, "ADA");
+///
+/// let greeting = Greeting { name: String::from("ADA") };
+/// let ButcheredGreeting { name } = Greeting::butcher(Cow::Borrowed(&greeting));
+/// assert!(matches!(name, Cow::Borrowed("ADA")));
+/// ```
+///
+/// [`Map`]: struct.Map.html
+pub trait ButcherTransform<'cow, T>
+where
+    T: 'cow,
+{
+    /// The type of the transformed field.
+    type Output: 'cow;
+
+    /// Transforms an owned field.
+    fn transform_owned(i: T) -> Self::Output;
+
+    /// Transforms a borrowed field.
+    fn transform_borrowed(i: &'cow T) -> Self::Output;
+
+    /// Creates back a field from its transformed value.
+    fn untransform(i: Self::Output) -> T;
+
+    /// Creates back a field from its transformed value, returning an error
+    /// if the value can't be converted back.
+    ///
+    /// This is called by [`Butcher::try_unbutcher`]. By default, it calls
+    /// `untransform`, which never fails.
+    ///
+    /// [`Butcher::try_unbutcher`]: ../trait.Butcher.html#method.try_unbutcher
+    fn try_untransform(i: Self::Output) -> Result<T, UnbutcherError> {
+        Ok(Self::untransform(i))
+    }
 }
 
 /// Define the behaviour of a specific field of a struct or enum when it is
@@ -252,7 +1123,7 @@ where
 /// automatically implemented.
 pub trait ButcherField<'cow, T>
 where
-    T: 'cow + Clone,
+    T: 'cow,
 {
     /// The method which will be used.
     type Method: ButcheringMethod<'cow, T>;
@@ -268,4 +1139,125 @@ where
     fn unbutcher(i: <Self::Method as ButcheringMethod<'cow, T>>::Output) -> T {
         <Self::Method as ButcheringMethod<'cow, T>>::unbutcher(i)
     }
+
+    fn try_unbutcher(
+        i: <Self::Method as ButcheringMethod<'cow, T>>::Output,
+    ) -> Result<T, UnbutcherError> {
+        <Self::Method as ButcheringMethod<'cow, T>>::try_unbutcher(i)
+    }
+}
+
+#[cfg(test)]
+mod unnest_method {
+    use super::*;
+
+    #[test]
+    fn from_borrowed() {
+        let field: Cow<str> = Cow::Owned(String::from("foo"));
+        let output = <UnnestMethod as ButcheringMethod<_>>::from_borrowed(&field);
+
+        assert!(matches!(output, Cow::Borrowed("foo")));
+    }
+
+    #[test]
+    fn from_owned() {
+        let borrowed: Cow<str> = Cow::Borrowed("foo");
+        let output = <UnnestMethod as ButcheringMethod<_>>::from_owned(borrowed);
+        assert!(matches!(output, Cow::Borrowed("foo")));
+
+        let owned: Cow<str> = Cow::Owned(String::from("bar"));
+        let output = <UnnestMethod as ButcheringMethod<_>>::from_owned(owned);
+        assert!(matches!(output, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn unbutcher() {
+        let output: Cow<str> = Cow::Borrowed("foo");
+        let field: Cow<'static, str> = <UnnestMethod as ButcheringMethod<_>>::unbutcher(output);
+
+        assert_eq!(field, "foo");
+    }
+}
+
+#[cfg(test)]
+mod option_ref {
+    use super::*;
+
+    #[test]
+    fn borrowed_round_trip() {
+        let pointed = String::from("foo");
+        let field = Some(&pointed);
+
+        let output = <OptionRef as ButcheringMethod<_>>::from_owned(field);
+        assert!(matches!(output, Some(Cow::Borrowed(_))));
+
+        let field = <OptionRef as ButcheringMethod<_>>::unbutcher(output);
+        assert!(std::ptr::eq(field.unwrap(), &pointed));
+    }
+
+    #[test]
+    fn owned_try_unbutcher() {
+        let output: Option<Cow<String>> = Some(Cow::Owned(String::from("foo")));
+        let field = <OptionRef as ButcheringMethod<Option<&String>>>::try_unbutcher(output);
+
+        assert!(field.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "an owned value can't be turned back into a reference")]
+    fn owned_unbutcher() {
+        let output: Option<Cow<String>> = Some(Cow::Owned(String::from("foo")));
+        <OptionRef as ButcheringMethod<Option<&String>>>::unbutcher(output);
+    }
+
+    #[test]
+    fn none() {
+        let field = <OptionRef as ButcheringMethod<Option<&u32>>>::unbutcher(None);
+        assert!(field.is_none());
+    }
+}
+
+#[cfg(test)]
+mod weak {
+    use super::*;
+
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    struct NoClone;
+
+    #[test]
+    fn live_reference() {
+        let pointed = Rc::new(String::from("foo"));
+        let field = Rc::downgrade(&pointed);
+
+        let output = <Weak as ButcheringMethod<_>>::from_borrowed(&field);
+        assert!(Rc::ptr_eq(&output.unwrap(), &pointed));
+    }
+
+    #[test]
+    fn round_trip() {
+        let pointed = Arc::new(NoClone);
+        let field = Arc::downgrade(&pointed);
+
+        let output = <Weak as ButcheringMethod<_>>::from_owned(field);
+        let field: sync::Weak<NoClone> = <Weak as ButcheringMethod<_>>::unbutcher(output);
+        assert!(Arc::ptr_eq(&field.upgrade().unwrap(), &pointed));
+    }
+
+    #[test]
+    fn dead_reference() {
+        let pointed = Arc::new(String::from("foo"));
+        let field = Arc::downgrade(&pointed);
+        drop(pointed);
+
+        let output = <Weak as ButcheringMethod<_>>::from_owned(field);
+        assert_eq!(output, None);
+    }
+
+    #[test]
+    fn unbutcher_dead_reference() {
+        let field: rc::Weak<u32> = <Weak as ButcheringMethod<_>>::unbutcher(None);
+        assert!(field.upgrade().is_none());
+    }
 }
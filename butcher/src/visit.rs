@@ -0,0 +1,70 @@
+//! Allows to visit each field of a butchered struct.
+//!
+//! See the documentation for [`ButcherVisit`] for more.
+//!
+//! [`ButcherVisit`]: trait.ButcherVisit.html
+
+/// Allows to visit each field of a butchered struct, without knowing its
+/// concrete type.
+///
+/// This trait is implemented by the `Butcher` derive macro for each butchered
+/// struct.
+///
+/// # Example
+///
+/// ```rust
+/// use butcher::Butcher;
+/// use butcher::visit::{ButcherVisit, FieldVisitor};
+/// use std::borrow::Cow;
+///
+/// #[derive(Butcher, Clone)]
+/// struct KeyPress {
+///     key: char,
+///     #[butcher(copy)]
+///     repeat: u8,
+///     modifiers: Vec<String>,
+/// }
+///
+/// struct FieldNames(Vec<&'static str>);
+///
+/// impl FieldVisitor for FieldNames {
+///     fn visit<T: ?Sized>(&mut self, name: &'static str, _: &T) {
+///         self.0.push(name);
+///     }
+/// }
+///
+/// let press = KeyPress { key: 'a', repeat: 1, modifiers: Vec::new() };
+/// let butchered = KeyPress::butcher(Cow::Borrowed(&press));
+///
+/// let mut names = FieldNames(Vec::new());
+/// butchered.visit_fields(&mut names);
+///
+/// assert_eq!(names.0, ["key", "repeat", "modifiers"]);
+/// # // Tuple structs and raw identifiers must be supported as well.
+/// # #[derive(Butcher, Clone)]
+/// # struct Pair<T>(T, u8);
+/// # #[derive(Butcher, Clone)]
+/// # struct Raw { r#type: u8, visitor: u8 }
+/// # let mut names = FieldNames(Vec::new());
+/// # Pair::butcher(Cow::Owned(Pair(1_u32, 2))).visit_fields(&mut names);
+/// # Raw::butcher(Cow::Owned(Raw { r#type: 0, visitor: 1 })).visit_fields(&mut names);
+/// # assert_eq!(names.0, ["0", "1", "type", "visitor"]);
+/// ```
+pub trait ButcherVisit {
+    /// Calls [`FieldVisitor::visit`] for each field, in declaration order.
+    ///
+    /// [`FieldVisitor::visit`]: trait.FieldVisitor.html#tymethod.visit
+    fn visit_fields<V: FieldVisitor>(&self, visitor: &mut V);
+}
+
+/// A visitor, which is called for each field of a butchered struct.
+///
+/// See the documentation for [`ButcherVisit`] for more.
+///
+/// [`ButcherVisit`]: trait.ButcherVisit.html
+pub trait FieldVisitor {
+    /// Visits a field.
+    ///
+    /// `name` is the name of the field, or its index for tuple structs.
+    fn visit<T: ?Sized>(&mut self, name: &'static str, field: &T);
+}
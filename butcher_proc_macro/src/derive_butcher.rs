@@ -3,10 +3,12 @@ use std::{
     fmt::{self, Display},
 };
 
+use quote::quote;
 use syn::{Data, DataUnion, DeriveInput};
 
 use proc_macro2::TokenStream;
 
+mod container;
 mod enums;
 mod field;
 mod structs;
@@ -22,22 +24,30 @@ pub enum DeriveError {
     MultipleButcheringMethod,
     FoundImplTrait,
     FoundMacroAsType,
-    FoundTraitObject,
     UnknownMethod,
+    UnknownSkipOption,
+    UnknownContainerOption,
+    UnchainableMethod,
+    KindOnStruct,
 }
 
 impl Display for DeriveError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            DeriveError::FoundUnion => "Butcher does not support unions",
+            DeriveError::FoundUnion => "Butcher cannot be derived for unions",
             DeriveError::FoundUnitStruct => "Butchering is useless for unit structs",
             DeriveError::MultipleButcheringMethod => {
                 "Multiple butchering method provided. Choose one!"
             }
             DeriveError::FoundImplTrait => "Butcher does not support impl Trait",
             DeriveError::FoundMacroAsType => "Butcher does not support macro as type",
-            DeriveError::FoundTraitObject => "Butcher does not support trait objects",
             DeriveError::UnknownMethod => "Unknown butchering method",
+            DeriveError::UnknownSkipOption => "Unknown skip option",
+            DeriveError::UnknownContainerOption => "Unknown container option",
+            DeriveError::UnchainableMethod => {
+                "Only regular, as_deref and unbox can be followed by another butchering method"
+            }
+            DeriveError::KindOnStruct => "The kind option is only available on enums",
         }
         .fmt(f)
     }
@@ -50,12 +60,53 @@ pub(super) fn try_from(i: DeriveInput) -> Result<TokenStream, syn::Error> {
         Data::Struct(_) => ButcheredStruct::from(i)?.expand_to_code(),
         Data::Enum(_) => ButcheredEnum::from(i)?.expand_to_code(),
         Data::Union(DataUnion { union_token, .. }) => {
+            let ident = &i.ident;
             return Err(syn::Error::new_spanned(
-                union_token,
+                quote! { #union_token #ident },
                 DeriveError::FoundUnion,
-            ))
+            ));
         }
     };
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+
+    use syn::parse_quote;
+
+    // The compile_fail doctest in the butcher crate can't check the error
+    // message, so this is where it is checked.
+    #[test]
+    fn union() {
+        let input: DeriveInput = parse_quote! {
+            union Foo {
+                a: u32,
+                b: f32,
+            }
+        };
+
+        let error = try_from(input).unwrap_err();
+
+        assert_eq!(error.to_string(), "Butcher cannot be derived for unions");
+    }
+
+    #[test]
+    fn kind_on_struct() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(kind = "FooKind")]
+            struct Foo {
+                a: u32,
+            }
+        };
+
+        let error = try_from(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "The kind option is only available on enums"
+        );
+    }
+}
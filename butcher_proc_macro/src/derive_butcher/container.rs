@@ -0,0 +1,279 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, Ident, LitStr, Path, Result as SynResult, Token,
+};
+
+use super::DeriveError;
+
+/// The options which are specified on the struct or enum itself.
+pub(super) struct ContainerMetadata {
+    /// The path of the `butcher` crate, used in the generated code.
+    pub krate: Path,
+    /// Whether the container is `Copy`, either because of `#[butcher(copy)]`
+    /// or because `Copy` is derived in a `#[derive(...)]` attribute which is
+    /// visible by the derive macro. In this case, the fields use the copy
+    /// method by default.
+    pub is_copy: bool,
+    /// The type names listed in `#[butcher(as_deref_types(...))]`. The fields
+    /// whose type starts with one of them use the as_deref method by default.
+    pub as_deref_types: Vec<Ident>,
+    /// The name specified in `#[butcher(alias = "...")]`. A type alias with
+    /// this name is generated for the butchered type.
+    pub alias: Option<Ident>,
+    /// The name specified in `#[butcher(kind = "...")]`. A fieldless enum with
+    /// this name is generated, along with the `kind` and `matches_variant`
+    /// methods. This is only allowed on enums.
+    pub kind: Option<Ident>,
+}
+
+impl ContainerMetadata {
+    pub(super) fn from_attrs(attrs: &[Attribute]) -> Result<ContainerMetadata, syn::Error> {
+        // The compiler only shows the `#[derive(...)]` attributes which are
+        // placed after the one which invokes this macro.
+        let is_copy = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("derive"))
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .any(|path| is_copy_trait(&path));
+
+        let mut metadata = ContainerMetadata {
+            is_copy,
+            ..ContainerMetadata::default()
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("butcher")) {
+            let options =
+                attr.parse_args_with(Punctuated::<ContainerOption, Token![,]>::parse_terminated)?;
+
+            for option in options {
+                match option {
+                    ContainerOption::Crate(path) => metadata.krate = path,
+                    ContainerOption::AsDerefTypes(types) => metadata.as_deref_types.extend(types),
+                    ContainerOption::Alias(alias) => metadata.alias = Some(alias),
+                    ContainerOption::Kind(kind) => metadata.kind = Some(kind),
+                    ContainerOption::Copy => metadata.is_copy = true,
+                }
+            }
+        }
+
+        Ok(metadata)
+    }
+}
+
+impl Default for ContainerMetadata {
+    fn default() -> ContainerMetadata {
+        ContainerMetadata {
+            krate: parse_quote! { ::butcher },
+            is_copy: false,
+            as_deref_types: Vec::new(),
+            alias: None,
+            kind: None,
+        }
+    }
+}
+
+enum ContainerOption {
+    Crate(Path),
+    AsDerefTypes(Vec<Ident>),
+    Alias(Ident),
+    Kind(Ident),
+    Copy,
+}
+
+impl Parse for ContainerOption {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if input.peek(Token![crate]) {
+            input.parse::<Token![crate]>()?;
+            input.parse::<Token![=]>()?;
+            let path = input.call(Path::parse_mod_style)?;
+
+            Ok(ContainerOption::Crate(path))
+        } else if matches!(input.fork().parse::<Ident>(), Ok(i) if i == "as_deref_types") {
+            input.parse::<Ident>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let types = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+            Ok(ContainerOption::AsDerefTypes(types.into_iter().collect()))
+        } else if matches!(input.fork().parse::<Ident>(), Ok(i) if i == "alias") {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let alias = input.parse::<LitStr>()?.parse::<Ident>()?;
+
+            Ok(ContainerOption::Alias(alias))
+        } else if matches!(input.fork().parse::<Ident>(), Ok(i) if i == "kind") {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let kind = input.parse::<LitStr>()?.parse::<Ident>()?;
+
+            Ok(ContainerOption::Kind(kind))
+        } else if matches!(input.fork().parse::<Ident>(), Ok(i) if i == "copy") {
+            input.parse::<Ident>()?;
+
+            Ok(ContainerOption::Copy)
+        } else {
+            Err(input.error(DeriveError::UnknownContainerOption))
+        }
+    }
+}
+
+/// Returns whether `path` is `Copy`, or its full path starting with `std` or
+/// `core`.
+///
+/// Other paths ending with `Copy` are not recognized, as they may be
+/// user-defined derive macros with the same name.
+fn is_copy_trait(path: &Path) -> bool {
+    let segments = path.segments.iter().collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [name] => path.leading_colon.is_none() && name.ident == "Copy",
+        [krate, module, name] => {
+            (krate.ident == "std" || krate.ident == "core")
+                && module.ident == "marker"
+                && name.ident == "Copy"
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod container_metadata {
+    use super::*;
+
+    use syn::DeriveInput;
+
+    fn krate_of(input: DeriveInput) -> Path {
+        ContainerMetadata::from_attrs(&input.attrs).unwrap().krate
+    }
+
+    fn is_copy_of(input: DeriveInput) -> bool {
+        ContainerMetadata::from_attrs(&input.attrs).unwrap().is_copy
+    }
+
+    #[test]
+    fn default_crate() {
+        let input: DeriveInput = parse_quote! { struct Foo; };
+        let left = krate_of(input);
+        let right: Path = parse_quote! { ::butcher };
+
+        assert_eq_tt!(left, right);
+    }
+
+    #[test]
+    fn renamed_crate() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(crate = my::butcher_alias)]
+            struct Foo;
+        };
+        let left = krate_of(input);
+        let right: Path = parse_quote! { my::butcher_alias };
+
+        assert_eq_tt!(left, right);
+    }
+
+    #[test]
+    fn derives_copy() {
+        let input: DeriveInput = parse_quote! {
+            #[derive(Clone, std::marker::Copy)]
+            struct Foo;
+        };
+        assert!(is_copy_of(input));
+
+        let input: DeriveInput = parse_quote! {
+            #[derive(Clone, Copy)]
+            struct Foo;
+        };
+        assert!(is_copy_of(input));
+
+        let input: DeriveInput = parse_quote! {
+            #[derive(Clone)]
+            struct Foo;
+        };
+        assert!(!is_copy_of(input));
+    }
+
+    #[test]
+    fn derives_user_defined_copy() {
+        let input: DeriveInput = parse_quote! {
+            #[derive(Clone, my_macros::Copy)]
+            struct Foo;
+        };
+        assert!(!is_copy_of(input));
+    }
+
+    #[test]
+    fn copy_option() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(copy)]
+            struct Foo;
+        };
+        assert!(is_copy_of(input));
+    }
+
+    #[test]
+    fn as_deref_types() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(as_deref_types(Name, Text), crate = butcher)]
+            struct Foo;
+        };
+        let metadata = ContainerMetadata::from_attrs(&input.attrs).unwrap();
+        let left = metadata.as_deref_types;
+        let right: Vec<Ident> = vec![parse_quote! { Name }, parse_quote! { Text }];
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn alias() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(alias = "FooButchered")]
+            struct Foo;
+        };
+        let metadata = ContainerMetadata::from_attrs(&input.attrs).unwrap();
+        let right: Ident = parse_quote! { FooButchered };
+
+        assert_eq!(metadata.alias, Some(right));
+
+        let input: DeriveInput = parse_quote! {
+            #[butcher(alias = "not an ident")]
+            struct Foo;
+        };
+
+        assert!(ContainerMetadata::from_attrs(&input.attrs).is_err());
+    }
+
+    #[test]
+    fn kind() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(kind = "TokenKind")]
+            enum Token {}
+        };
+        let metadata = ContainerMetadata::from_attrs(&input.attrs).unwrap();
+        let right: Ident = parse_quote! { TokenKind };
+
+        assert_eq!(metadata.kind, Some(right));
+
+        let input: DeriveInput = parse_quote! { enum Token {} };
+
+        assert!(ContainerMetadata::from_attrs(&input.attrs)
+            .unwrap()
+            .kind
+            .is_none());
+    }
+
+    #[test]
+    fn unknown_option() {
+        let input: DeriveInput = parse_quote! {
+            #[butcher(foo = bar)]
+            struct Foo;
+        };
+
+        assert!(ContainerMetadata::from_attrs(&input.attrs).is_err());
+    }
+}
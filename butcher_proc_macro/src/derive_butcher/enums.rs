@@ -1,38 +1,45 @@
-use std::collections::HashSet;
-
 use syn::{
-    punctuated::Punctuated, Data, DeriveInput, Fields, GenericParam, Ident, Lifetime, LifetimeDef,
-    Type, TypeParam, Variant as SVariant, Visibility, WhereClause,
+    punctuated::Punctuated, ConstParam, Data, DeriveInput, Fields, GenericParam, Ident,
+    LifetimeDef, Path, Type, TypeParam, Variant as SVariant, Visibility, WherePredicate,
 };
 
 use proc_macro2::TokenStream;
 
 use quote::{format_ident, quote};
 
-use super::field::Field;
+use super::{container::ContainerMetadata, field::Field};
 
 use crate::utils;
 
-use super::utils::create_type_signature;
+use super::utils::{
+    collect_combining_errors, container_predicates, create_type_signature, expand_alias,
+    expand_cow_conversions, fresh_lifetime, snake_case_ident, strip_generic_param,
+    ContainerGenerics,
+};
 
 pub(super) struct ButcheredEnum {
     name: Ident,
     vis: Visibility,
     variants: Vec<Variant>,
     generics_for_butchered: Vec<GenericParam>,
-    where_clause_for_butchered: Option<WhereClause>,
+    predicates: Vec<WherePredicate>,
+    krate: Path,
+    alias: Option<Ident>,
+    kind: Option<Ident>,
 }
 
 impl ButcheredEnum {
     pub(super) fn from(input: DeriveInput) -> Result<ButcheredEnum, syn::Error> {
         let self_type_signature = create_type_signature(&input);
+        let metadata = ContainerMetadata::from_attrs(&input.attrs)?;
+        let krate = metadata.krate.clone();
 
         let name = input.ident;
         let vis = input.vis;
 
         let generics_for_butchered = input.generics.params.iter().cloned().collect::<Vec<_>>();
 
-        let where_clause_for_butchered = input.generics.where_clause;
+        let predicates = container_predicates(&input.generics);
 
         let data = match input.data {
             Data::Enum(d) => d,
@@ -40,62 +47,215 @@ impl ButcheredEnum {
             Data::Struct(_) | Data::Union(_) => unreachable!(),
         };
 
-        let mut generic_types = HashSet::new();
-        let mut lifetimes = HashSet::new();
-
-        input.generics.params.into_iter().for_each(|g| match g {
-            GenericParam::Type(t) => {
-                generic_types.insert(t.ident);
-            }
-            GenericParam::Lifetime(lt) => {
-                lifetimes.insert(lt.lifetime);
-            }
-            GenericParam::Const(_) => {}
-        });
+        let container_generics = ContainerGenerics::from(&input.generics);
 
-        let variants = data
-            .variants
-            .into_iter()
-            .map(|v| Variant::from(v, &generic_types, &lifetimes, &self_type_signature))
-            .fold(Ok(Vec::new()), |acc, res| match (acc, res) {
-                (Ok(mut xs), Ok(x)) => {
-                    xs.push(x);
-                    Ok(xs)
-                }
-                (Ok(_), Err(e)) => Err(e),
-                (Err(mut main_err), Err(e)) => {
-                    main_err.combine(e);
-                    Err(main_err)
-                }
-                (tmp @ Err(_), Ok(_)) => tmp,
-            })?;
+        let variants = collect_combining_errors(data.variants.into_iter().map(|v| {
+            Variant::from(
+                v,
+                &container_generics,
+                &self_type_signature,
+                &predicates,
+                &metadata,
+            )
+        }))?;
 
         Ok(ButcheredEnum {
             name,
             vis,
             variants,
             generics_for_butchered,
-            where_clause_for_butchered,
+            predicates,
+            krate,
+            alias: metadata.alias,
+            kind: metadata.kind,
         })
     }
 
     pub(super) fn expand_to_code(self) -> TokenStream {
-        let lt = quote! { 'cow };
+        let lt = fresh_lifetime("cow", &self.generics_for_butchered);
         let enum_declaration = self.expand_enum_declaration(&lt);
         let butcher_fields_implementation = self.expand_fields(&lt);
         let butcher_implementation = self.expand_butcher_implementation(&lt);
+        let cow_conversions = self.expand_cow_conversions(&lt);
+        let kind = self.expand_kind(&lt);
+        let field_names = self.expand_field_names(&lt);
+        let constructors = self.expand_constructors(&lt);
+        let alias = expand_alias(
+            &self.vis,
+            self.alias.as_ref(),
+            &self.enum_name(),
+            &self.generics_for_butchered,
+            &lt,
+        );
 
         quote! {
             #enum_declaration
             #butcher_fields_implementation
             #butcher_implementation
+            #cow_conversions
+            #kind
+            #field_names
+            #constructors
+            #alias
+        }
+    }
+
+    /// Generates a constructor for each variant of the butchered enum, named
+    /// after the variant in snake case.
+    ///
+    /// Variants whose constructor would collide with the `field_names` method,
+    /// or with the `kind` and `matches_variant` methods when they are
+    /// generated, don't get one. Neither do
+    /// the variants whose names only differ by their case, such as `IOError`
+    /// and `IoError`, as they would get constructors with the same name.
+    ///
+    /// The constructors are `const` when the impl block has no bound, as trait
+    /// bounds are not allowed on `const fn` with our MSRV.
+    fn expand_constructors(&self, lt: &TokenStream) -> TokenStream {
+        let vis = &self.vis;
+        let constness = if self.has_bounds() {
+            TokenStream::new()
+        } else {
+            quote! { const }
+        };
+        let enum_name = self.enum_name();
+        let generics_declaration = self.generics_declaration(lt);
+        let generics = self.generics(lt);
+        let where_items = self
+            .provided_where_clause_items()
+            .chain(self.required_where_clause_items(lt));
+
+        let names = self
+            .variants
+            .iter()
+            .map(|v| snake_case_ident(&v.name))
+            .collect::<Vec<_>>();
+
+        let constructors = names
+            .iter()
+            .zip(self.variants.iter())
+            .filter(|(constructor, _)| {
+                let collides_with_kind = self.kind.is_some()
+                    && (*constructor == "kind" || *constructor == "matches_variant");
+
+                !collides_with_kind
+                    && *constructor != "field_names"
+                    && names.iter().filter(|name| name == constructor).count() == 1
+            })
+            .map(|(constructor, v)| {
+                v.expand_constructor(constructor, &enum_name, vis, &constness, lt)
+            });
+
+        quote! {
+            impl #generics_declaration #enum_name #generics
+            where
+                #( #where_items ),*
+            {
+                #( #constructors )*
+            }
+        }
+    }
+
+    /// Generates the fieldless enum requested with `#[butcher(kind = "...")]`,
+    /// along with the `kind` and `matches_variant` methods, if any.
+    fn expand_kind(&self, lt: &TokenStream) -> TokenStream {
+        let kind_name = match &self.kind {
+            Some(kind_name) => kind_name,
+            None => return TokenStream::new(),
+        };
+
+        let vis = &self.vis;
+        let enum_name = self.enum_name();
+        let generics_declaration = self.generics_declaration(lt);
+        let generics = self.generics(lt);
+        let where_items = self
+            .provided_where_clause_items()
+            .chain(self.required_where_clause_items(lt));
+
+        let variants = self.variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+
+        quote! {
+            #[derive(
+                ::std::clone::Clone,
+                ::std::marker::Copy,
+                ::std::fmt::Debug,
+                ::std::cmp::PartialEq,
+                ::std::cmp::Eq,
+                ::std::hash::Hash,
+            )]
+            #vis enum #kind_name {
+                #( #variants ),*
+            }
+
+            impl #generics_declaration #enum_name #generics
+            where
+                #( #where_items ),*
+            {
+                #[allow(dead_code)]
+                #vis fn kind(&self) -> #kind_name {
+                    match *self {
+                        #( #enum_name::#variants { .. } => #kind_name::#variants ),*
+                    }
+                }
+
+                #[allow(dead_code)]
+                #vis fn matches_variant(&self, kind: #kind_name) -> bool {
+                    self.kind() == kind
+                }
+            }
         }
     }
 
+    fn expand_field_names(&self, lt: &TokenStream) -> TokenStream {
+        let vis = &self.vis;
+        let enum_name = self.enum_name();
+        let generics_declaration = self.generics_declaration(lt);
+        let generics = self.generics(lt);
+        let where_items = self
+            .provided_where_clause_items()
+            .chain(self.required_where_clause_items(lt));
+
+        let variants = self.variants.iter().map(|v| &v.name);
+        let field_names = self.variants.iter().map(|v| {
+            let names = v.fields.iter().map(|f| f.name.to_lit_str());
+            quote! { &[ #( #names ),* ] }
+        });
+
+        quote! {
+            impl #generics_declaration #enum_name #generics
+            where
+                #( #where_items ),*
+            {
+                #[allow(dead_code)]
+                #vis fn field_names(&self) -> &'static [&'static str] {
+                    match *self {
+                        #( #enum_name::#variants { .. } => #field_names ),*
+                    }
+                }
+            }
+        }
+    }
+
+    fn expand_cow_conversions(&self, lt: &TokenStream) -> TokenStream {
+        let generics_declaration = self.generics_declaration(lt);
+
+        let name = &self.name;
+        let initial_generics = self.initial_generics();
+        let self_type = quote! { #name #initial_generics };
+
+        let where_items = self
+            .provided_where_clause_items()
+            .chain(self.required_where_clause_items(lt));
+        let where_clause = quote! { where #( #where_items ),* };
+
+        expand_cow_conversions(&generics_declaration, &self_type, &where_clause, lt)
+    }
+
     fn expand_enum_declaration(&self, lt: &TokenStream) -> TokenStream {
         let vis = &self.vis;
         let name = self.enum_name();
-        let generics = self.generics_declaration(lt);
+        let generics = self.generics_for_butchered.iter();
+        let generics = quote! { < #lt #( , #generics )* > };
         let where_items = self
             .provided_where_clause_items()
             .chain(self.required_where_clause_items(lt));
@@ -103,7 +263,7 @@ impl ButcheredEnum {
         let variants = self.variants.iter().map(|v| v.expand_in_enum(lt));
 
         quote! {
-            #[derive(Clone)]
+            #[derive(::std::clone::Clone)]
             #vis enum #name #generics
             where
                 #(
@@ -116,7 +276,7 @@ impl ButcheredEnum {
     }
 
     fn generics_declaration(&self, lt: &TokenStream) -> TokenStream {
-        let generics = self.generics_for_butchered.iter().map(|g| quote! { #g });
+        let generics = self.generics_for_butchered.iter().map(strip_generic_param);
 
         quote! { < #lt #( , #generics )* > }
     }
@@ -125,8 +285,7 @@ impl ButcheredEnum {
         let generics = self.generics_for_butchered.iter().map(|g| match g {
             GenericParam::Type(TypeParam { ident, .. }) => quote! { #ident },
             GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => quote! { #lifetime },
-            // TODO: find how this should be done.
-            GenericParam::Const(_) => unimplemented!(),
+            GenericParam::Const(ConstParam { ident, .. }) => quote! { #ident },
         });
 
         quote! { < #lt #( , #generics )* > }
@@ -136,8 +295,7 @@ impl ButcheredEnum {
         let generics = self.generics_for_butchered.iter().map(|g| match g {
             GenericParam::Type(TypeParam { ident, .. }) => quote! { #ident },
             GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => quote! { #lifetime },
-            // TODO: find how this should be done.
-            GenericParam::Const(_) => unimplemented!(),
+            GenericParam::Const(ConstParam { ident, .. }) => quote! { #ident },
         });
 
         quote! { < #( #generics ),* > }
@@ -159,6 +317,7 @@ impl ButcheredEnum {
     }
 
     fn expand_butcher_implementation(&self, lt: &TokenStream) -> TokenStream {
+        let krate = &self.krate;
         let generic_declaration = self.generics_declaration(lt);
         let name = &self.name;
         let initial_generics = self.initial_generics();
@@ -178,28 +337,36 @@ impl ButcheredEnum {
 
         let generics_items = self
             .provided_where_clause_items()
-            .chain(self.required_where_clause_items(lt));
+            .chain(self.required_where_clause_items(lt))
+            .collect::<Vec<_>>();
 
         let unbutcher_match_arms = self
             .variants
             .iter()
-            .map(|v| v.unbutcher_match_arm(&self.name, lt));
+            .map(|v| v.unbutcher_match_arm(&self.name, lt, false));
+        let try_unbutcher_match_arms = self
+            .variants
+            .iter()
+            .map(|v| v.unbutcher_match_arm(&self.name, lt, true));
 
         quote! {
+            // Deprecated variants and fields are accessed when the enum is
+            // destructured or recreated.
+            #[allow(deprecated)]
             impl #generic_declaration
-                butcher::Butcher< #lt >
+                #krate::Butcher< #lt >
                 for #name #initial_generics
             where
                 #( #generics_items ),*
             {
                 type Output = #enum_name #generics;
 
-                fn butcher(this: std::borrow::Cow<#lt, Self>) -> Self::Output {
+                fn butcher(this: ::std::borrow::Cow<#lt, Self>) -> Self::Output {
                     match this {
-                        std::borrow::Cow::Owned(this) => match this {
+                        ::std::borrow::Cow::Owned(this) => match this {
                             #( #owned_arms, )*
                         },
-                        std::borrow::Cow::Borrowed(this) => match this {
+                        ::std::borrow::Cow::Borrowed(this) => match this {
                             #( #borrowed_arms, )*
                         },
                     }
@@ -210,12 +377,55 @@ impl ButcheredEnum {
                         #( #unbutcher_match_arms ),*
                     }
                 }
+
+                fn try_unbutcher(this: Self::Output) -> ::std::result::Result<Self, #krate::UnbutcherError> {
+                    ::std::result::Result::Ok(match this {
+                        #( #try_unbutcher_match_arms ),*
+                    })
+                }
+            }
+
+            impl #generic_declaration
+                ::std::convert::TryFrom<#enum_name #generics>
+                for #name #initial_generics
+            where
+                #( #generics_items ),*
+            {
+                type Error = #krate::UnbutcherError;
+
+                fn try_from(this: #enum_name #generics) -> ::std::result::Result<Self, Self::Error> {
+                    <Self as #krate::Butcher<#lt>>::try_unbutcher(this)
+                }
+            }
+
+            // The butchered fields can't be turned back into a reference to
+            // the initial enum, so the resulting `Cow` is always owned.
+            impl #generic_declaration
+                ::std::convert::From<#enum_name #generics>
+                for ::std::borrow::Cow<#lt, #name #initial_generics>
+            where
+                #( #generics_items ),*
+            {
+                fn from(this: #enum_name #generics) -> Self {
+                    ::std::borrow::Cow::Owned(<#name #initial_generics as #krate::Butcher<#lt>>::unbutcher(this))
+                }
             }
         }
     }
 
+    /// Returns whether the generated impl blocks have a where clause. This is
+    /// the case when the enum has a type or a lifetime parameter, or a where
+    /// clause.
+    fn has_bounds(&self) -> bool {
+        !self.predicates.is_empty()
+            || self
+                .generics_for_butchered
+                .iter()
+                .any(|generic| !matches!(generic, GenericParam::Const(_)))
+    }
+
     fn provided_where_clause_items(&self) -> impl Iterator<Item = TokenStream> + '_ {
-        self.where_clause_for_butchered
+        self.predicates
             .iter()
             .map(|predicate| quote! { #predicate })
     }
@@ -227,12 +437,20 @@ impl ButcheredEnum {
         self.generics_for_butchered
             .iter()
             .flat_map(move |generic| match generic {
-                GenericParam::Type(TypeParam { ident, .. }) => Some(quote! { #ident: Clone + #lt }),
+                GenericParam::Type(TypeParam { ident, .. }) => {
+                    Some(quote! { #ident: ::std::clone::Clone + #lt })
+                }
                 GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => {
                     Some(quote! { #lifetime: #lt })
                 }
                 GenericParam::Const(_) => None,
             })
+            .chain(
+                self.variants
+                    .iter()
+                    .flat_map(|v| v.fields.iter())
+                    .flat_map(move |f| f.required_lifetime_bounds(lt)),
+            )
     }
 }
 
@@ -244,14 +462,16 @@ struct Variant {
     // vis: Visibility,
     kind: VariantKind,
     fields: Vec<Field>,
+    krate: Path,
 }
 
 impl Variant {
     fn from(
         v: SVariant,
-        generic_types: &HashSet<Ident>,
-        lifetimes: &HashSet<Lifetime>,
+        container_generics: &ContainerGenerics,
         enum_type_signature: &Type,
+        container_predicates: &[WherePredicate],
+        metadata: &ContainerMetadata,
     ) -> Result<Variant, syn::Error> {
         let name = v.ident;
 
@@ -261,24 +481,23 @@ impl Variant {
             Fields::Unit => (Vec::new(), VariantKind::Unit),
         };
 
-        let fields = fields
-            .into_iter()
-            .enumerate()
-            .map(|(id, f)| Field::from(f, &generic_types, &lifetimes, id, enum_type_signature))
-            .fold(Ok(Vec::new()), |acc, res| match (acc, res) {
-                (Ok(mut main), Ok(v)) => {
-                    main.push(v);
-                    Ok(main)
-                }
-                (Ok(_), Err(e)) => Err(e),
-                (Err(mut main_err), Err(e)) => {
-                    main_err.combine(e);
-                    Err(main_err)
-                }
-                (tmp @ Err(_), Ok(_)) => tmp,
-            })?;
-
-        Ok(Variant { name, kind, fields })
+        let fields = collect_combining_errors(fields.into_iter().enumerate().map(|(id, f)| {
+            Field::from(
+                f,
+                container_generics,
+                id,
+                enum_type_signature,
+                container_predicates,
+                metadata,
+            )
+        }))?;
+
+        Ok(Variant {
+            name,
+            kind,
+            fields,
+            krate: metadata.krate.clone(),
+        })
     }
 
     fn expand_in_enum(&self, lt: &TokenStream) -> TokenStream {
@@ -306,13 +525,37 @@ impl Variant {
     fn expand_fields(&self, lt: &TokenStream, main_name: &Ident) -> TokenStream {
         let name = format_ident!("{}{}", main_name, self.name);
 
-        let expanded_fields = self.fields.iter().map(|f| f.expand_to_code(&name, &lt));
+        let expanded_fields = self.fields.iter().map(|f| f.expand_to_code(&name, lt));
 
         quote! {
             #( #expanded_fields )*
         }
     }
 
+    fn expand_constructor(
+        &self,
+        constructor: &Ident,
+        butchered_enum_name: &Ident,
+        vis: &Visibility,
+        constness: &TokenStream,
+        lt: &TokenStream,
+    ) -> TokenStream {
+        let parameters = self.fields.iter().map(|f| {
+            let name = f.name.expand_as_pattern_identifier();
+            let (_, ty, _) = f.associated_main_struct_data(lt);
+            quote! { #name: #ty }
+        });
+
+        let value = self.pattern(butchered_enum_name);
+
+        quote! {
+            #[allow(dead_code, clippy::too_many_arguments)]
+            #vis #constness fn #constructor( #( #parameters ),* ) -> Self {
+                #value
+            }
+        }
+    }
+
     fn pattern(&self, main_enum_name: &Ident) -> TokenStream {
         let variant_name = &self.name;
         let fields = self
@@ -388,6 +631,7 @@ impl Variant {
         method: TokenStream,
         lt: &TokenStream,
     ) -> TokenStream {
+        let krate = &self.krate;
         let variant = &self.name;
         let fields = self
             .fields
@@ -411,24 +655,29 @@ impl Variant {
             VariantKind::Named => quote! {
                 #main_enum_name :: #variant {
                     #(
-                        #fields: < #associated_struct as butcher::methods::ButcherField<#lt, #associated_struct_types>>:: #method ( #fields_2)
+                        #fields: < #associated_struct as #krate::methods::ButcherField<#lt, #associated_struct_types>>:: #method ( #fields_2)
                     ),*
                 }
             },
             VariantKind::Unnamed => quote! {
                 #main_enum_name :: #variant (
                     #(
-                        < #associated_struct as butcher::methods::ButcherField<#lt, #associated_struct_types>>:: #method ( #fields )
+                        < #associated_struct as #krate::methods::ButcherField<#lt, #associated_struct_types>>:: #method ( #fields )
                     ),*
                 )
             },
         }
     }
 
-    fn unbutcher_match_arm(&self, enum_name: &Ident, lt: &TokenStream) -> TokenStream {
+    fn unbutcher_match_arm(
+        &self,
+        enum_name: &Ident,
+        lt: &TokenStream,
+        fallible: bool,
+    ) -> TokenStream {
         let butchered_enum_name = utils::global_associated_struct_name(enum_name);
         let pattern = self.unbutcher_match_arm_pattern(&butchered_enum_name);
-        let own_each_field = self.own_each_field(enum_name, lt);
+        let own_each_field = self.own_each_field(enum_name, lt, fallible);
         let initial_struct = self.recreate_initial_struct(enum_name);
 
         quote! {
@@ -461,7 +710,13 @@ impl Variant {
         }
     }
 
-    fn own_each_field(&self, enum_name: &Ident, lt: &TokenStream) -> TokenStream {
+    fn own_each_field(&self, enum_name: &Ident, lt: &TokenStream, fallible: bool) -> TokenStream {
+        let krate = &self.krate;
+        let (unbutcher, question_mark) = if fallible {
+            (quote! { try_unbutcher }, quote! { ? })
+        } else {
+            (quote! { unbutcher }, TokenStream::new())
+        };
         let names = self
             .fields
             .iter()
@@ -480,7 +735,7 @@ impl Variant {
         quote! {
             let ( #( #names ),* ) =
                 ( #(
-                    < #associated_structs as butcher::methods::ButcherField< #lt, #associated_struct_types >>::unbutcher( #names2 )
+                    < #associated_structs as #krate::methods::ButcherField< #lt, #associated_struct_types >>::#unbutcher( #names2 ) #question_mark
                 ),* )
         }
     }
@@ -1,10 +1,13 @@
-use std::{collections::HashSet, iter};
+use std::{collections::HashSet, fmt};
 
 use syn::{
+    parenthesized,
     parse::{Parse, ParseStream},
-    AngleBracketedGenericArguments, Attribute, GenericArgument, Ident, Lifetime, PathArguments,
-    QSelf, Result as SynResult, ReturnType, Token, Type, TypeArray, TypeBareFn, TypeGroup,
-    TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTuple, Visibility,
+    parse_quote, token, AngleBracketedGenericArguments, Attribute, Binding, ConstParam, Expr,
+    GenericArgument, Ident, Lifetime, ParenthesizedGenericArguments, Path, PathArguments, QSelf,
+    Result as SynResult, ReturnType, Token, TraitBound, Type, TypeArray, TypeBareFn, TypeGroup,
+    TypeParamBound, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject,
+    TypeTuple, Visibility, WherePredicate,
 };
 
 use quote::{quote, ToTokens};
@@ -12,41 +15,59 @@ use quote::{quote, ToTokens};
 use proc_macro2::TokenStream;
 
 use crate::{
-    derive_butcher::{utils::ReplaceSelf, DeriveError},
+    derive_butcher::{
+        container::ContainerMetadata,
+        utils::{dedup_predicates, mentioned_idents, ContainerGenerics, ReplaceSelf},
+        DeriveError,
+    },
     utils::{self, FieldName},
 };
 
 fn cow() -> TokenStream {
-    quote! { std::borrow::Cow }
-}
-
-fn butcher_field() -> TokenStream {
-    quote! { butcher::methods::ButcherField }
+    quote! { ::std::borrow::Cow }
 }
 
 fn phantom() -> TokenStream {
-    quote! { std::marker::PhantomData }
+    quote! { ::std::marker::PhantomData }
 }
 
 pub(super) struct Field {
     pub name: FieldName,
-    pub method: ButcheringMethod,
+    pub methods: Vec<ButcheringMethod>,
     pub vis: Visibility,
     pub ty: Type,
     pub associated_generics: Vec<Ident>,
     pub associated_lifetimes: Vec<Lifetime>,
+    pub associated_consts: Vec<ConstParam>,
     additional_traits: Option<TokenStream>,
+    inherited_predicates: Vec<WherePredicate>,
+    krate: Path,
 }
 
 impl Field {
     pub(super) fn from(
         input: syn::Field,
-        generic_types: &HashSet<Ident>,
-        lifetimes: &HashSet<Lifetime>,
+        container_generics: &ContainerGenerics,
         id: usize,
         main_struct_type: &Type,
+        container_predicates: &[WherePredicate],
+        metadata: &ContainerMetadata,
     ) -> Result<Field, syn::Error> {
-        let FieldMetadata(method, additional_traits) = parse_meta_attrs(input.attrs.as_slice())?;
+        let default_method = if starts_with_any(&input.ty, &metadata.as_deref_types) {
+            ButcheringMethod::AsDeref
+        } else if metadata.is_copy || is_known_copy_type(&input.ty) {
+            ButcheringMethod::Copy
+        } else {
+            ButcheringMethod::Regular
+        };
+        let FieldMetadata(mut methods, additional_traits) =
+            parse_meta_attrs(input.attrs.as_slice(), default_method)?;
+
+        // Applying as_deref on a `Cow` would clone the data borrowed by an
+        // owned struct. The nested `Cow` is flattened instead.
+        if methods.first() == Some(&ButcheringMethod::AsDeref) && is_cow_type(&input.ty) {
+            methods[0] = ButcheringMethod::Unnest;
+        }
 
         let vis = input.vis;
 
@@ -60,7 +81,21 @@ impl Field {
         // between contexts.
         ty.replace(main_struct_type);
 
-        let mut associated_generics = find_generics_in_type(&ty, generic_types)?;
+        let ContainerGenerics {
+            types,
+            lifetimes,
+            consts,
+        } = container_generics;
+
+        // Const generics are searched along with the generic types, as they
+        // may be written as a type in generic arguments.
+        let generic_types = types
+            .iter()
+            .chain(consts.iter().map(|c| &c.ident))
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        let mut associated_generics = find_generics_in_type(&ty, &generic_types)?;
         let mut associated_lifetimes = find_lifetimes_in_type(&ty, lifetimes)?;
 
         associated_generics.sort_unstable();
@@ -69,14 +104,43 @@ impl Field {
         associated_generics.dedup();
         associated_lifetimes.dedup();
 
+        // The predicates of the initial type are required by the butchering
+        // method as well. Only the ones which do not involve generics which
+        // are unrelated to this field can be added.
+        let inherited_predicates = container_predicates
+            .iter()
+            .filter(|predicate| {
+                mentioned_idents(quote! { #predicate }).iter().all(|ident| {
+                    let is_unrelated_type =
+                        generic_types.contains(ident) && !associated_generics.contains(ident);
+                    let is_unrelated_lifetime = lifetimes
+                        .iter()
+                        .any(|lt| &lt.ident == ident && !associated_lifetimes.contains(lt));
+
+                    !is_unrelated_type && !is_unrelated_lifetime
+                })
+            })
+            .cloned()
+            .collect();
+
+        let associated_consts = consts
+            .iter()
+            .filter(|c| associated_generics.contains(&c.ident))
+            .map(|ConstParam { ident, ty, .. }| parse_quote! { const #ident: #ty })
+            .collect();
+        associated_generics.retain(|ident| types.contains(ident));
+
         Ok(Field {
             vis,
-            method,
+            methods,
             name,
             ty,
             associated_generics,
             associated_lifetimes,
+            associated_consts,
             additional_traits,
+            inherited_predicates,
+            krate: metadata.krate.clone(),
         })
     }
 
@@ -92,7 +156,8 @@ impl Field {
 
     pub(super) fn associated_struct_declaration(&self, main_struct_name: &Ident) -> TokenStream {
         let vis = &self.vis;
-        let struct_with_generics = self.associated_struct_with_generics(main_struct_name);
+        let struct_name = self.associated_struct_name(main_struct_name);
+        let generics_declaration = self.associated_generics_declaration();
 
         let types_in_phantom = self.associated_generics.iter();
         let lifetimes_in_phantom = self.associated_lifetimes_in_phantom();
@@ -101,7 +166,7 @@ impl Field {
 
         quote! {
             #[allow(non_camel_case_types)]
-            #vis struct #struct_with_generics
+            #vis struct #struct_name < #generics_declaration >
                 (
                     #phantom< ( #( #types_in_phantom, )* ) > ,
                     #phantom< ( #( #lifetimes_in_phantom, )* ) > ,
@@ -112,10 +177,21 @@ impl Field {
     pub(super) fn associated_struct_with_generics(&self, main_struct_name: &Ident) -> TokenStream {
         let struct_name = self.associated_struct_name(main_struct_name);
 
-        let lifetimes_declaration = self.associated_lifetimes.as_slice();
-        let generics_declaration = self.associated_generics.as_slice();
+        let lifetimes = self.associated_lifetimes.as_slice();
+        let generics = self.associated_generics.as_slice();
+        let consts = self.associated_consts.iter().map(|c| &c.ident);
+
+        quote! { #struct_name < #( #lifetimes, )* #( #generics, )* #( #consts, )* > }
+    }
+
+    /// Declares the generic parameters of the associated struct, which are the
+    /// ones used in the type of the field.
+    fn associated_generics_declaration(&self) -> TokenStream {
+        let lifetimes = self.associated_lifetimes.as_slice();
+        let generics = self.associated_generics.as_slice();
+        let consts = self.associated_consts.as_slice();
 
-        quote! { #struct_name < #( #lifetimes_declaration, )* #( #generics_declaration, )* > }
+        quote! { #( #lifetimes, )* #( #generics, )* #( #consts, )* }
     }
 
     fn associated_struct_name(&self, main_struct_name: &Ident) -> Ident {
@@ -133,22 +209,21 @@ impl Field {
         main_struct_name: &Ident,
         lt: &TokenStream,
     ) -> TokenStream {
-        let butcher_field = butcher_field();
+        let krate = &self.krate;
         let struct_with_generics = self.associated_struct_with_generics(main_struct_name);
 
-        let generic_types = self.associated_generics.as_slice();
-        let lifetimes = self.associated_lifetimes.as_slice();
+        let generics_declaration = self.associated_generics_declaration();
 
-        let where_clause = self.where_clause_trait(&lt);
+        let where_clause = self.where_clause_trait(lt);
 
         let input_type = &self.ty;
 
-        let method = self.method.associated_method_name();
+        let method = self.associated_method_name();
 
         quote! {
             impl
-                <#lt, #( #lifetimes, )* #( #generic_types ),*>
-                #butcher_field<#lt, #input_type> for #struct_with_generics
+                <#lt, #generics_declaration>
+                #krate::methods::ButcherField<#lt, #input_type> for #struct_with_generics
                 #where_clause
             {
                 type Method = #method;
@@ -160,7 +235,7 @@ impl Field {
         &'a self,
         lt: &'a TokenStream,
     ) -> impl Iterator<Item = TokenStream> + 'a {
-        let required_by_method = self.method.required_traits_for(&self.ty, lt);
+        let required_by_method = self.required_traits(lt);
 
         let bounds_for_generic_types = self
             .associated_generics
@@ -171,14 +246,35 @@ impl Field {
             .iter()
             .map(move |l| quote! { #l: #lt });
 
-        iter::once(required_by_method)
+        required_by_method
             .chain(bounds_for_generic_types)
             .chain(bounds_for_lifetimes)
+            .chain(self.required_lifetime_bounds(lt))
             .chain(self.additional_traits.clone())
+            .chain(
+                self.inherited_predicates
+                    .iter()
+                    .map(|predicate| quote! { #predicate }),
+            )
+    }
+
+    /// Returns the bounds which must hold for the container to implement
+    /// `Butcher`.
+    ///
+    /// The option ref method borrows for the lifetime of the reference, which
+    /// must then be the same as the butchering lifetime.
+    pub(super) fn required_lifetime_bounds<'a>(
+        &'a self,
+        lt: &'a TokenStream,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        self.associated_lifetimes
+            .iter()
+            .filter(move |_| self.methods.contains(&ButcheringMethod::OptionRef))
+            .map(move |l| quote! { #lt: #l })
     }
 
     fn where_clause_trait(&self, lt: &TokenStream) -> TokenStream {
-        let items = self.where_clause_items(lt);
+        let items = dedup_predicates(self.where_clause_items(lt));
 
         quote! {
             where
@@ -187,7 +283,81 @@ impl Field {
     }
 
     fn output_type_unwrapped(&self, lt: &TokenStream) -> TokenStream {
-        self.method.output_type_unwrapped(&self.ty, lt)
+        let (method, ty) = self.steps().pop().unwrap();
+        method.output_type_unwrapped(&ty, lt, &self.krate)
+    }
+
+    /// Returns each butchering method applied on the field, with the type it is
+    /// applied on.
+    fn steps(&self) -> Vec<(ButcheringMethod, TokenStream)> {
+        let ty = &self.ty;
+        let mut ty = quote! { #ty };
+
+        self.methods
+            .iter()
+            .map(|method| {
+                let next_ty = method.chained_type(&ty);
+                (method.clone(), std::mem::replace(&mut ty, next_ty))
+            })
+            .collect()
+    }
+
+    fn required_traits<'a>(
+        &'a self,
+        lt: &'a TokenStream,
+    ) -> impl Iterator<Item = TokenStream> + 'a {
+        let steps = self.steps();
+
+        let required_by_steps = steps
+            .iter()
+            .map(|(method, ty)| method.required_traits_for(ty, lt, &self.krate))
+            .collect::<Vec<_>>();
+
+        // Every intermediate type must be cloneable, as required by the Chain
+        // method.
+        let required_by_chain = steps
+            .into_iter()
+            .skip(1)
+            .map(move |(_, ty)| quote! { #ty: ::std::clone::Clone + #lt });
+
+        required_by_steps.into_iter().chain(required_by_chain)
+    }
+
+    fn associated_method_name(&self) -> TokenStream {
+        let krate = &self.krate;
+        let mut steps = self.steps().into_iter().rev();
+
+        let (last, mut mid) = steps.next().unwrap();
+        let last = last.associated_method_name(krate);
+
+        steps.fold(last, |then, (first, ty)| {
+            let first = first.associated_method_name(krate);
+            let chained = quote! { #krate::methods::Chain<#first, #then, #mid> };
+            mid = ty;
+            chained
+        })
+    }
+
+    fn last_method(&self) -> &ButcheringMethod {
+        self.methods.last().unwrap()
+    }
+
+    pub(super) fn reborrow_expr(&self, field: &TokenStream) -> TokenStream {
+        self.last_method().reborrow_expr(field, &self.krate)
+    }
+
+    /// Returns whether the field is only butchered with the copy method.
+    pub(super) fn is_copied(&self) -> bool {
+        self.methods == [ButcheringMethod::Copy]
+    }
+
+    pub(super) fn is_reborrowable(&self) -> bool {
+        !matches!(
+            self.last_method(),
+            ButcheringMethod::OptionRef
+                | ButcheringMethod::Rebutcher
+                | ButcheringMethod::Transform(_)
+        )
     }
 
     pub(super) fn associated_main_struct_data(
@@ -198,16 +368,99 @@ impl Field {
     }
 }
 
-fn parse_meta_attrs(input: &[Attribute]) -> Result<FieldMetadata, syn::Error> {
+/// The small `Copy` types of the standard library, whose fields use the copy
+/// method by default, with the module they are defined in.
+const KNOWN_COPY_TYPES: &[(&str, &str)] = &[
+    ("time", "Duration"),
+    ("time", "Instant"),
+    ("time", "SystemTime"),
+    ("net", "IpAddr"),
+    ("net", "Ipv4Addr"),
+    ("net", "Ipv6Addr"),
+    ("net", "SocketAddr"),
+    ("net", "SocketAddrV4"),
+    ("net", "SocketAddrV6"),
+];
+
+/// Returns whether `ty` is one of the `KNOWN_COPY_TYPES`, written with its
+/// full path starting with `std` or `core`.
+///
+/// Imported types are not recognized, as they may be user-defined types with
+/// the same name.
+fn is_known_copy_type(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path,
+        _ => return false,
+    };
+
+    let segments = path.segments.iter().collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [krate, module, name] => {
+            (krate.ident == "std" || krate.ident == "core")
+                && krate.arguments.is_empty()
+                && module.arguments.is_empty()
+                && name.arguments.is_empty()
+                && KNOWN_COPY_TYPES
+                    .iter()
+                    .any(|(m, n)| module.ident == m && name.ident == n)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `ty` is a `Cow`, either written as `Cow` or with its full
+/// path starting with `std` or `alloc`.
+///
+/// Unlike the `KNOWN_COPY_TYPES`, a lone `Cow` is assumed to be the one of the
+/// standard library, as it is almost always imported. A user-defined type
+/// named `Cow` must be written with a path, such as `self::Cow`.
+fn is_cow_type(ty: &Type) -> bool {
+    let path = match ty {
+        Type::Path(TypePath { qself: None, path }) => path,
+        _ => return false,
+    };
+
+    let segments = path.segments.iter().collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        [name] => path.leading_colon.is_none() && name.ident == "Cow",
+        [krate, module, name] => {
+            (krate.ident == "std" || krate.ident == "alloc")
+                && krate.arguments.is_empty()
+                && module.ident == "borrow"
+                && module.arguments.is_empty()
+                && name.ident == "Cow"
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the first segment of the path of `ty` is one of `names`.
+///
+/// Type aliases can't be resolved by the derive macro, so this is how the
+/// aliases listed in `#[butcher(as_deref_types(...))]` are recognized.
+fn starts_with_any(ty: &Type, names: &[Ident]) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => {
+            matches!(path.segments.first(), Some(first) if names.contains(&first.ident))
+        }
+        _ => false,
+    }
+}
+
+fn parse_meta_attrs(
+    input: &[Attribute],
+    default_method: ButcheringMethod,
+) -> Result<FieldMetadata, syn::Error> {
     let methods = input
         .iter()
-        .map(parse_meta_attr)
-        .flatten()
+        .filter_map(parse_meta_attr)
         .collect::<Result<Vec<_>, _>>()?;
 
     match methods.as_slice() {
         [(_, metadata)] => Ok(metadata.clone()),
-        [] => Ok(FieldMetadata(ButcheringMethod::Regular, None)),
+        [] => Ok(FieldMetadata(vec![default_method], None)),
         [.., (last, _)] => Err(syn::Error::new_spanned(
             last,
             DeriveError::MultipleButcheringMethod,
@@ -224,32 +477,56 @@ fn parse_meta_attr(attr: &Attribute) -> Option<Result<(&Attribute, FieldMetadata
 }
 
 #[derive(Clone, Debug)]
-struct FieldMetadata(ButcheringMethod, Option<TokenStream>);
+struct FieldMetadata(Vec<ButcheringMethod>, Option<TokenStream>);
 
 impl Parse for FieldMetadata {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let method = input.parse::<ButcheringMethod>()?;
+        let mut methods = vec![input.parse::<ButcheringMethod>()?];
+
+        let traits = loop {
+            if input.is_empty() {
+                break None;
+            }
 
-        let traits = if input.is_empty() {
-            None
-        } else {
             let _ = input.parse::<Token![,]>()?;
-            Some(input.parse::<TokenStream>()?)
+
+            // Multiple butchering methods can be chained. The remaining tokens
+            // are the additional trait bounds.
+            let span = input.span();
+            match parse_chained_method(input) {
+                Some(method) if methods.last().unwrap().is_chainable() => methods.push(method),
+                Some(_) => return Err(syn::Error::new(span, DeriveError::UnchainableMethod)),
+                None => break Some(input.parse::<TokenStream>()?),
+            }
         };
 
-        Ok(FieldMetadata(method, traits))
+        Ok(FieldMetadata(methods, traits))
+    }
+}
+
+fn parse_chained_method(input: ParseStream) -> Option<ButcheringMethod> {
+    let fork = input.fork();
+
+    match fork.parse::<ButcheringMethod>() {
+        Ok(_) if fork.is_empty() || fork.peek(Token![,]) => input.parse().ok(),
+        _ => None,
     }
 }
 
 fn find_generics_in_type(ty: &Type, generics: &HashSet<Ident>) -> Result<Vec<Ident>, syn::Error> {
     match ty {
-        Type::Array(TypeArray { elem, .. })
-        | Type::Group(TypeGroup { elem, .. })
+        Type::Group(TypeGroup { elem, .. })
         | Type::Paren(TypeParen { elem, .. })
         | Type::Ptr(TypePtr { elem, .. })
         | Type::Reference(TypeReference { elem, .. })
         | Type::Slice(TypeSlice { elem, .. }) => find_generics_in_type(elem.as_ref(), generics),
 
+        Type::Array(TypeArray { elem, len, .. }) => {
+            let mut found_generics = find_generics_in_type(elem.as_ref(), generics)?;
+            found_generics.extend(find_generics_in_expr(len, generics));
+            Ok(found_generics)
+        }
+
         Type::Tuple(TypeTuple { elems, .. }) => elems
             .into_iter()
             .map(|ty| find_generics_in_type(ty, generics))
@@ -269,22 +546,7 @@ fn find_generics_in_type(ty: &Type, generics: &HashSet<Ident>) -> Result<Vec<Ide
         }
 
         Type::Path(TypePath { path, qself }) => {
-            let mut found_generics = path
-                .segments
-                .iter()
-                .filter_map(|s| match &s.arguments {
-                    PathArguments::None | PathArguments::Parenthesized(_) => None,
-                    PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                        args, ..
-                    }) => Some(args),
-                })
-                .flatten()
-                .filter_map(|arg| match arg {
-                    GenericArgument::Type(t) => Some(t),
-                    _ => None,
-                })
-                .map(|t| find_generics_in_type(t, generics))
-                .try_fold(Vec::new(), extend_discovered)?;
+            let mut found_generics = find_generics_in_path(path, generics)?;
 
             match path.get_ident() {
                 Some(id) if generics.contains(id) => found_generics.push(id.clone()),
@@ -302,7 +564,15 @@ fn find_generics_in_type(ty: &Type, generics: &HashSet<Ident>) -> Result<Vec<Ide
 
         Type::Macro(m) => Err(syn::Error::new_spanned(m, DeriveError::FoundMacroAsType)),
 
-        Type::TraitObject(to) => Err(syn::Error::new_spanned(to, DeriveError::FoundTraitObject)),
+        Type::TraitObject(TypeTraitObject { bounds, .. }) => bounds
+            .iter()
+            .filter_map(|bound| match bound {
+                TypeParamBound::Trait(TraitBound { path, .. }) => {
+                    Some(find_generics_in_path(path, generics))
+                }
+                TypeParamBound::Lifetime(_) => None,
+            })
+            .try_fold(Vec::new(), extend_discovered),
 
         // For the next three arms, the compiler is going to raise an error
         // anyway.
@@ -314,6 +584,65 @@ fn find_generics_in_type(ty: &Type, generics: &HashSet<Ident>) -> Result<Vec<Ide
     }
 }
 
+fn find_generics_in_path(path: &Path, generics: &HashSet<Ident>) -> Result<Vec<Ident>, syn::Error> {
+    let in_const_arguments = path
+        .segments
+        .iter()
+        .filter_map(|s| match &s.arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                Some(args)
+            }
+            PathArguments::None | PathArguments::Parenthesized(_) => None,
+        })
+        .flatten()
+        .filter_map(|arg| match arg {
+            GenericArgument::Const(expr) => Some(find_generics_in_expr(expr, generics)),
+            _ => None,
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    path.segments
+        .iter()
+        .flat_map(|s| types_in_arguments(&s.arguments))
+        .map(|t| find_generics_in_type(t, generics))
+        .try_fold(in_const_arguments, extend_discovered)
+}
+
+/// Returns the const generics used in an expression, such as the length of an
+/// array.
+fn find_generics_in_expr(expr: &Expr, generics: &HashSet<Ident>) -> Vec<Ident> {
+    mentioned_idents(quote! { #expr })
+        .into_iter()
+        .filter(|ident| generics.contains(ident))
+        .collect()
+}
+
+/// Returns the types contained in the generic arguments of a path segment,
+/// including the arguments and the return type of the `Fn` traits.
+fn types_in_arguments(arguments: &PathArguments) -> Vec<&Type> {
+    match arguments {
+        PathArguments::None => Vec::new(),
+        PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(t) => Some(t),
+                // Associated types, such as `Item` in `dyn Iterator<Item = T>`.
+                GenericArgument::Binding(Binding { ty, .. }) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        PathArguments::Parenthesized(ParenthesizedGenericArguments { inputs, output, .. }) => {
+            let output = match output {
+                ReturnType::Type(_, ty) => Some(ty.as_ref()),
+                ReturnType::Default => None,
+            };
+
+            inputs.iter().chain(output).collect()
+        }
+    }
+}
+
 fn find_lifetimes_in_type(ty: &Type, lts: &HashSet<Lifetime>) -> Result<Vec<Lifetime>, syn::Error> {
     match ty {
         Type::Array(TypeArray { elem, .. })
@@ -352,22 +681,7 @@ fn find_lifetimes_in_type(ty: &Type, lts: &HashSet<Lifetime>) -> Result<Vec<Life
         }
 
         Type::Path(TypePath { path, qself }) => {
-            let mut found_lifetimes = path
-                .segments
-                .iter()
-                .filter_map(|s| match &s.arguments {
-                    PathArguments::None | PathArguments::Parenthesized(_) => None,
-                    PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                        args, ..
-                    }) => Some(args),
-                })
-                .flatten()
-                .filter_map(|arg| match arg {
-                    GenericArgument::Lifetime(lt) if lts.contains(lt) => Some(Ok(vec![lt.clone()])),
-                    GenericArgument::Type(ty) => Some(find_lifetimes_in_type(ty, lts)),
-                    _ => None,
-                })
-                .try_fold(Vec::new(), extend_discovered)?;
+            let mut found_lifetimes = find_lifetimes_in_path(path, lts)?;
 
             if let Some(QSelf { ty, .. }) = qself {
                 found_lifetimes.extend(find_lifetimes_in_type(ty.as_ref(), lts)?);
@@ -380,7 +694,14 @@ fn find_lifetimes_in_type(ty: &Type, lts: &HashSet<Lifetime>) -> Result<Vec<Life
 
         Type::Macro(m) => Err(syn::Error::new_spanned(m, DeriveError::FoundMacroAsType)),
 
-        Type::TraitObject(to) => Err(syn::Error::new_spanned(to, DeriveError::FoundTraitObject)),
+        Type::TraitObject(TypeTraitObject { bounds, .. }) => bounds
+            .iter()
+            .map(|bound| match bound {
+                TypeParamBound::Trait(TraitBound { path, .. }) => find_lifetimes_in_path(path, lts),
+                TypeParamBound::Lifetime(lt) if lts.contains(lt) => Ok(vec![lt.clone()]),
+                TypeParamBound::Lifetime(_) => Ok(Vec::new()),
+            })
+            .try_fold(Vec::new(), extend_discovered),
 
         // For the next three arms, the compiler is going to raise an error
         // anyway.
@@ -392,6 +713,33 @@ fn find_lifetimes_in_type(ty: &Type, lts: &HashSet<Lifetime>) -> Result<Vec<Life
     }
 }
 
+fn find_lifetimes_in_path(
+    path: &Path,
+    lts: &HashSet<Lifetime>,
+) -> Result<Vec<Lifetime>, syn::Error> {
+    let in_lifetime_arguments = path
+        .segments
+        .iter()
+        .filter_map(|s| match &s.arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                Some(args)
+            }
+            PathArguments::None | PathArguments::Parenthesized(_) => None,
+        })
+        .flatten()
+        .filter_map(|arg| match arg {
+            GenericArgument::Lifetime(lt) if lts.contains(lt) => Some(lt.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    path.segments
+        .iter()
+        .flat_map(|s| types_in_arguments(&s.arguments))
+        .map(|t| find_lifetimes_in_type(t, lts))
+        .try_fold(in_lifetime_arguments, extend_discovered)
+}
+
 fn extend_discovered<T>(
     mut discovered: Vec<T>,
     to_add: Result<Vec<T>, syn::Error>,
@@ -401,42 +749,114 @@ fn extend_discovered<T>(
     Ok(discovered)
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub(super) enum ButcheringMethod {
     Copy,
     AsDeref,
+    FlattenVec,
+    IntoOwned,
+    MaybeOwned,
+    OptionRef,
     Rebutcher,
     Regular,
+    Skip(Option<MethodPath>),
+    Snapshot,
+    Transform(MethodPath),
     Unbox,
+    /// Used instead of `AsDeref` on `Cow` fields. It can't be selected with
+    /// an attribute.
+    Unnest,
+    Weak,
+}
+
+/// The path to a user-defined type, as written in
+/// `#[butcher(transform = ...)]` or `#[butcher(skip(default = ...))]`.
+#[derive(Clone)]
+pub(super) struct MethodPath(Path);
+
+impl fmt::Debug for MethodPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.to_token_stream())
+    }
+}
+
+impl PartialEq for MethodPath {
+    fn eq(&self, other: &MethodPath) -> bool {
+        self.0.to_token_stream().to_string() == other.0.to_token_stream().to_string()
+    }
 }
 
 impl ButcheringMethod {
-    fn required_traits_for(&self, ty: &Type, lt: &TokenStream) -> TokenStream {
+    fn required_traits_for(&self, ty: &TokenStream, lt: &TokenStream, krate: &Path) -> TokenStream {
         match self {
-            ButcheringMethod::Copy => quote! { #ty: Clone },
+            ButcheringMethod::Copy => quote! { #ty: ::std::clone::Clone },
             ButcheringMethod::AsDeref => {
-                quote! { #ty: Into<<<#ty as std::ops::Deref>::Target as ToOwned>::Owned> }
+                quote! { #ty: ::std::convert::Into<<<#ty as ::std::ops::Deref>::Target as ::std::borrow::ToOwned>::Owned> }
+            }
+            ButcheringMethod::FlattenVec => quote! { #ty: ::std::clone::Clone },
+            ButcheringMethod::IntoOwned => {
+                quote! { <<#ty as ::std::ops::Deref>::Target as ::std::borrow::ToOwned>::Owned: #lt }
             }
+            ButcheringMethod::MaybeOwned => quote! { #ty: ::std::clone::Clone },
+            ButcheringMethod::OptionRef => quote! { #ty: #krate::methods::OptionalReference },
             ButcheringMethod::Rebutcher => {
                 quote! {
-                    #ty : Butcher< #lt > + ToOwned<Owned = #ty > + #lt,
-                    <#ty as butcher::Butcher< #lt >>:: Output: Clone
+                    #ty : #krate::Butcher< #lt > + ::std::borrow::ToOwned<Owned = #ty > + #lt,
+                    <#ty as #krate::Butcher< #lt >>:: Output: ::std::clone::Clone
                 }
             }
-            ButcheringMethod::Regular => quote! { #ty: Clone },
-            ButcheringMethod::Unbox => quote! { <#ty as std::ops::Deref>::Target: Clone },
+            ButcheringMethod::Regular => quote! { #ty: ::std::clone::Clone },
+            ButcheringMethod::Skip(None) => quote! { #ty: ::std::default::Default },
+            ButcheringMethod::Skip(Some(MethodPath(path))) => {
+                quote! { #path: #krate::methods::SkipDefault< #ty > }
+            }
+            ButcheringMethod::Snapshot => quote! { #ty: #krate::methods::SnapshotValue },
+            ButcheringMethod::Transform(MethodPath(path)) => {
+                quote! {
+                    #path: #krate::methods::ButcherTransform< #lt, #ty >,
+                    <#path as #krate::methods::ButcherTransform< #lt, #ty >>::Output: ::std::clone::Clone
+                }
+            }
+            ButcheringMethod::Unbox => {
+                quote! { <#ty as ::std::ops::Deref>::Target: ::std::clone::Clone }
+            }
+            ButcheringMethod::Unnest => {
+                quote! { <<#ty as ::std::ops::Deref>::Target as ::std::borrow::ToOwned>::Owned: #lt }
+            }
+            ButcheringMethod::Weak => quote! { #ty: #krate::methods::WeakReference },
         }
     }
 
-    fn output_type_unwrapped(&self, ty: &Type, lt: &TokenStream) -> TokenStream {
+    fn output_type_unwrapped(
+        &self,
+        ty: &TokenStream,
+        lt: &TokenStream,
+        krate: &Path,
+    ) -> TokenStream {
         match self {
-            ButcheringMethod::Copy => quote! { #ty },
-            ButcheringMethod::AsDeref | ButcheringMethod::Unbox => {
+            // The output type of these methods does not depend on the lifetime.
+            // Writing it as a projection ensures that the lifetime is used in the
+            // butchered struct, even if every field uses one of these methods.
+            ButcheringMethod::Copy
+            | ButcheringMethod::FlattenVec
+            | ButcheringMethod::IntoOwned
+            | ButcheringMethod::MaybeOwned
+            | ButcheringMethod::Skip(_)
+            | ButcheringMethod::Snapshot
+            | ButcheringMethod::Transform(_)
+            | ButcheringMethod::Weak
+            // The output of this method borrows for the lifetime of the
+            // reference, so it is written as a projection as well.
+            | ButcheringMethod::OptionRef => {
+                let method = self.associated_method_name(krate);
+                quote! { <#method as #krate::methods::ButcheringMethod< #lt, #ty >>::Output }
+            }
+            ButcheringMethod::AsDeref | ButcheringMethod::Unbox | ButcheringMethod::Unnest => {
                 let cow = cow();
-                quote! { #cow < #lt , <#ty as std::ops::Deref>::Target > }
+                quote! { #cow < #lt , <#ty as ::std::ops::Deref>::Target > }
             }
             ButcheringMethod::Rebutcher => {
-                quote! { < #ty as Butcher< #lt >>::Output }
+                quote! { < #ty as #krate::Butcher< #lt >>::Output }
             }
             ButcheringMethod::Regular => {
                 let cow = cow();
@@ -445,13 +865,99 @@ impl ButcheringMethod {
         }
     }
 
-    fn associated_method_name(self) -> TokenStream {
+    /// Returns whether another butchering method can be applied on the output
+    /// of this method.
+    fn is_chainable(&self) -> bool {
+        match self {
+            ButcheringMethod::AsDeref
+            | ButcheringMethod::Regular
+            | ButcheringMethod::Unbox
+            | ButcheringMethod::Unnest => true,
+            ButcheringMethod::Copy
+            | ButcheringMethod::FlattenVec
+            | ButcheringMethod::IntoOwned
+            | ButcheringMethod::MaybeOwned
+            | ButcheringMethod::OptionRef
+            | ButcheringMethod::Rebutcher
+            | ButcheringMethod::Skip(_)
+            | ButcheringMethod::Snapshot
+            | ButcheringMethod::Transform(_)
+            | ButcheringMethod::Weak => false,
+        }
+    }
+
+    /// Returns the type on which the next butchering method is applied, when
+    /// this method is applied on `ty`.
+    fn chained_type(&self, ty: &TokenStream) -> TokenStream {
+        match self {
+            ButcheringMethod::AsDeref | ButcheringMethod::Unbox | ButcheringMethod::Unnest => {
+                quote! { <#ty as ::std::ops::Deref>::Target }
+            }
+            _ => ty.clone(),
+        }
+    }
+
+    /// Creates an expression which converts a reference to a butchered field
+    /// into the same butchered field, with a shorter lifetime.
+    ///
+    /// Fields which are not `Cow` can't be reborrowed, and are cloned
+    /// instead.
+    fn reborrow_expr(&self, field: &TokenStream, krate: &Path) -> TokenStream {
         match self {
-            ButcheringMethod::Copy => quote! { butcher::methods::Copy },
-            ButcheringMethod::AsDeref => quote! { butcher::methods::AsDeref },
-            ButcheringMethod::Rebutcher => quote! { butcher::methods::Rebutcher },
-            ButcheringMethod::Regular => quote! { butcher::methods::Regular },
-            ButcheringMethod::Unbox => quote! { butcher::methods::Unbox },
+            ButcheringMethod::AsDeref
+            | ButcheringMethod::Regular
+            | ButcheringMethod::Unbox
+            | ButcheringMethod::Unnest => {
+                let cow = cow();
+                quote! { #cow::Borrowed(::std::ops::Deref::deref(#field)) }
+            }
+            ButcheringMethod::Copy
+            | ButcheringMethod::IntoOwned
+            | ButcheringMethod::OptionRef
+            | ButcheringMethod::Skip(_)
+            | ButcheringMethod::Snapshot
+            | ButcheringMethod::Weak => {
+                quote! { ::std::clone::Clone::clone(#field) }
+            }
+            ButcheringMethod::FlattenVec => {
+                let cow = cow();
+                quote! {
+                    ::std::iter::Iterator::collect(::std::iter::Iterator::map(
+                        <[_]>::iter(#field),
+                        |element| #cow::Borrowed(::std::ops::Deref::deref(element)),
+                    ))
+                }
+            }
+            ButcheringMethod::MaybeOwned => {
+                quote! { #krate::maybe_owned::MaybeOwned::reborrow(#field) }
+            }
+            // The butchered output of a field can't be reborrowed generically.
+            // Structs containing such fields don't get a reborrow method.
+            ButcheringMethod::Rebutcher | ButcheringMethod::Transform(_) => unreachable!(),
+        }
+    }
+
+    fn associated_method_name(&self, krate: &Path) -> TokenStream {
+        match self {
+            ButcheringMethod::Copy => quote! { #krate::methods::Copy },
+            ButcheringMethod::AsDeref => quote! { #krate::methods::AsDeref },
+            ButcheringMethod::FlattenVec => quote! { #krate::methods::FlattenVec },
+            ButcheringMethod::IntoOwned => quote! { #krate::methods::IntoOwned },
+            ButcheringMethod::MaybeOwned => quote! { #krate::methods::MaybeOwnedMethod },
+            ButcheringMethod::OptionRef => quote! { #krate::methods::OptionRef },
+            ButcheringMethod::Rebutcher => quote! { #krate::methods::Rebutcher },
+            ButcheringMethod::Regular => quote! { #krate::methods::Regular },
+            ButcheringMethod::Skip(None) => quote! { #krate::methods::Skip },
+            ButcheringMethod::Skip(Some(MethodPath(path))) => {
+                quote! { #krate::methods::Skip<#path> }
+            }
+            ButcheringMethod::Snapshot => quote! { #krate::methods::Snapshot },
+            ButcheringMethod::Transform(MethodPath(path)) => {
+                quote! { #krate::methods::Map<#path> }
+            }
+            ButcheringMethod::Unbox => quote! { #krate::methods::Unbox },
+            ButcheringMethod::Unnest => quote! { #krate::methods::UnnestMethod },
+            ButcheringMethod::Weak => quote! { #krate::methods::Weak },
         }
     }
 }
@@ -464,14 +970,168 @@ impl Parse for ButcheringMethod {
             Ok(ButcheringMethod::Copy)
         } else if i == "as_deref" {
             Ok(ButcheringMethod::AsDeref)
+        } else if i == "into_owned" {
+            Ok(ButcheringMethod::IntoOwned)
+        } else if i == "maybe_owned" {
+            Ok(ButcheringMethod::MaybeOwned)
+        } else if i == "option_ref" {
+            Ok(ButcheringMethod::OptionRef)
         } else if i == "rebutcher" {
             Ok(ButcheringMethod::Rebutcher)
         } else if i == "regular" {
             Ok(ButcheringMethod::Regular)
+        } else if i == "skip" {
+            if !input.peek(token::Paren) {
+                return Ok(ButcheringMethod::Skip(None));
+            }
+
+            let content;
+            parenthesized!(content in input);
+            let option = content.parse::<Ident>()?;
+            if option != "default" {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    DeriveError::UnknownSkipOption,
+                ));
+            }
+            content.parse::<Token![=]>()?;
+            Ok(ButcheringMethod::Skip(Some(MethodPath(content.parse()?))))
+        } else if i == "snapshot" {
+            Ok(ButcheringMethod::Snapshot)
+        } else if i == "transform" {
+            input.parse::<Token![=]>()?;
+            Ok(ButcheringMethod::Transform(MethodPath(input.parse()?)))
         } else if i == "unbox" {
             Ok(ButcheringMethod::Unbox)
+        } else if i == "vec_cow" {
+            Ok(ButcheringMethod::FlattenVec)
+        } else if i == "weak" {
+            Ok(ButcheringMethod::Weak)
         } else {
             Err(syn::Error::new_spanned(i, DeriveError::UnknownMethod))
         }
     }
 }
+
+#[cfg(test)]
+mod known_copy_type {
+    use super::*;
+
+    #[test]
+    fn std_types() {
+        assert!(is_known_copy_type(&parse_quote! { std::time::Duration }));
+        assert!(is_known_copy_type(&parse_quote! { ::core::net::Ipv4Addr }));
+    }
+
+    #[test]
+    fn other_types() {
+        assert!(!is_known_copy_type(&parse_quote! { u32 }));
+        assert!(!is_known_copy_type(&parse_quote! { chrono::Duration }));
+        assert!(!is_known_copy_type(
+            &parse_quote! { Option<std::time::Duration> }
+        ));
+        assert!(!is_known_copy_type(&parse_quote! { std::net::Duration }));
+    }
+
+    #[test]
+    fn imported_types() {
+        // This may be a user-defined type.
+        assert!(!is_known_copy_type(&parse_quote! { Duration }));
+    }
+}
+
+#[cfg(test)]
+mod cow_type {
+    use super::*;
+
+    #[test]
+    fn std_cow() {
+        assert!(is_cow_type(&parse_quote! { std::borrow::Cow<'a, str> }));
+        assert!(is_cow_type(&parse_quote! { ::alloc::borrow::Cow<'a, T> }));
+    }
+
+    #[test]
+    fn imported_cow() {
+        assert!(is_cow_type(&parse_quote! { Cow<'a, str> }));
+    }
+
+    #[test]
+    fn other_types() {
+        assert!(!is_cow_type(&parse_quote! { self::Cow<'a, str> }));
+        assert!(!is_cow_type(&parse_quote! { my_crate::Cow<'a, str> }));
+        assert!(!is_cow_type(&parse_quote! { std::rc::Cow<'a, str> }));
+        assert!(!is_cow_type(&parse_quote! { Box<Cow<'a, str>> }));
+    }
+}
+
+#[cfg(test)]
+mod field_metadata {
+    use super::*;
+
+    #[test]
+    fn transform() {
+        let FieldMetadata(methods, traits) = syn::parse_str("transform = foo::Uppercase").unwrap();
+
+        assert_eq!(
+            methods,
+            [ButcheringMethod::Transform(MethodPath(
+                syn::parse_str("foo::Uppercase").unwrap()
+            ))]
+        );
+        assert!(traits.is_none());
+    }
+
+    #[test]
+    fn skip_with_default() {
+        let FieldMetadata(methods, traits) =
+            syn::parse_str("skip(default = foo::Identity)").unwrap();
+
+        assert_eq!(
+            methods,
+            [ButcheringMethod::Skip(Some(MethodPath(
+                syn::parse_str("foo::Identity").unwrap()
+            )))]
+        );
+        assert!(traits.is_none());
+    }
+
+    #[test]
+    fn skip_unknown_option() {
+        let error = syn::parse_str::<FieldMetadata>("skip(value = foo::Identity)").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            DeriveError::UnknownSkipOption.to_string()
+        );
+    }
+
+    #[test]
+    fn single_method() {
+        let FieldMetadata(methods, traits) = syn::parse_str("as_deref").unwrap();
+
+        assert_eq!(methods, [ButcheringMethod::AsDeref]);
+        assert!(traits.is_none());
+    }
+
+    #[test]
+    fn chained_methods_with_traits() {
+        let FieldMetadata(methods, traits) =
+            syn::parse_str("unbox, as_deref, T: Clone, U: Copy").unwrap();
+
+        assert_eq!(
+            methods,
+            [ButcheringMethod::Unbox, ButcheringMethod::AsDeref]
+        );
+        assert_eq!(traits.unwrap().to_string(), "T : Clone , U : Copy");
+    }
+
+    #[test]
+    fn unchainable_method() {
+        let error = syn::parse_str::<FieldMetadata>("copy, as_deref").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            DeriveError::UnchainableMethod.to_string()
+        );
+    }
+}
@@ -1,7 +1,8 @@
-use std::{collections::HashSet, iter};
+use std::iter;
 
 use syn::{
-    Data, DeriveInput, Fields, GenericParam, Ident, LifetimeDef, TypeParam, Visibility, WhereClause,
+    ConstParam, Data, DeriveInput, Fields, GenericParam, Ident, LifetimeDef, Path, TypeParam,
+    Visibility, WherePredicate,
 };
 
 use quote::quote;
@@ -10,27 +11,45 @@ use proc_macro2::TokenStream;
 
 use crate::utils;
 
-use super::{field::Field, utils::create_type_signature, DeriveError};
+use super::{
+    container::ContainerMetadata,
+    field::Field,
+    utils::{
+        collect_combining_errors, container_predicates, create_type_signature, dedup_predicates,
+        expand_alias, expand_cow_conversions, fresh_lifetime, strip_generic_param,
+        ContainerGenerics,
+    },
+    DeriveError,
+};
 
 pub(super) struct ButcheredStruct {
     name: Ident,
     fields: Vec<Field>,
     vis: Visibility,
     generics_for_butchered: Vec<GenericParam>,
-    where_clause_for_butchered: Option<WhereClause>,
+    predicates: Vec<WherePredicate>,
     kind: StructKind,
+    krate: Path,
+    alias: Option<Ident>,
+    is_copy: bool,
 }
 
 impl ButcheredStruct {
     pub(super) fn from(input: DeriveInput) -> Result<ButcheredStruct, syn::Error> {
         let self_type_signature = create_type_signature(&input);
+        let metadata = ContainerMetadata::from_attrs(&input.attrs)?;
+        let krate = metadata.krate.clone();
+
+        if let Some(kind) = &metadata.kind {
+            return Err(syn::Error::new(kind.span(), DeriveError::KindOnStruct));
+        }
 
         let name = input.ident;
         let vis = input.vis;
 
         let generics_for_butchered = input.generics.params.iter().cloned().collect::<Vec<_>>();
 
-        let where_clause_for_butchered = input.generics.where_clause;
+        let predicates = container_predicates(&input.generics);
 
         let data = match input.data {
             Data::Struct(d) => d,
@@ -45,48 +64,34 @@ impl ButcheredStruct {
         }
         .map_err(|(e, s)| syn::Error::new(s, e))?;
 
-        let mut generic_types = HashSet::new();
-        let mut lifetimes = HashSet::new();
-
-        input.generics.params.into_iter().for_each(|g| match g {
-            GenericParam::Type(t) => {
-                generic_types.insert(t.ident);
-            }
-            GenericParam::Lifetime(lt) => {
-                lifetimes.insert(lt.lifetime);
-            }
-            GenericParam::Const(_) => {}
-        });
+        let container_generics = ContainerGenerics::from(&input.generics);
 
-        let fields = fields
-            .into_iter()
-            .enumerate()
-            .map(|(id, f)| Field::from(f, &generic_types, &lifetimes, id, &self_type_signature))
-            .fold(Ok(Vec::new()), |acc, res| match (acc, res) {
-                (Ok(mut main), Ok(v)) => {
-                    main.push(v);
-                    Ok(main)
-                }
-                (Ok(_), Err(e)) => Err(e),
-                (Err(mut main_err), Err(e)) => {
-                    main_err.combine(e);
-                    Err(main_err)
-                }
-                (tmp @ Err(_), Ok(_)) => tmp,
-            })?;
+        let fields = collect_combining_errors(fields.into_iter().enumerate().map(|(id, f)| {
+            Field::from(
+                f,
+                &container_generics,
+                id,
+                &self_type_signature,
+                &predicates,
+                &metadata,
+            )
+        }))?;
 
         Ok(ButcheredStruct {
             name,
             fields,
             vis,
             generics_for_butchered,
-            where_clause_for_butchered,
+            predicates,
             kind,
+            krate,
+            alias: metadata.alias,
+            is_copy: metadata.is_copy,
         })
     }
 
     pub(crate) fn expand_to_code(self) -> TokenStream {
-        let lt = quote! { 'cow };
+        let lt = fresh_lifetime("cow", &self.generics_for_butchered);
         let fields_expansion = self
             .fields
             .iter()
@@ -94,15 +99,224 @@ impl ButcheredStruct {
 
         let butchered_struct = self.expand_butchered_struct(&lt);
         let butchered_struct_trait = self.expand_butchered_struct_trait(&lt);
+        let cow_conversions = self.expand_cow_conversions(&lt);
+        let reborrow = self.expand_reborrow(&lt);
+        let to_owned_struct = self.expand_to_owned_struct(&lt);
+        let visit = self.expand_visit(&lt);
+        let len = self.expand_len(&lt);
+        let field_names = self.expand_field_names(&lt);
+        let alias = expand_alias(
+            &self.vis,
+            self.alias.as_ref(),
+            &utils::global_associated_struct_name(&self.name),
+            &self.generics_for_butchered,
+            &lt,
+        );
 
         quote! {
             #( #fields_expansion )*
 
             #butchered_struct_trait
             #butchered_struct
+            #cow_conversions
+            #reborrow
+            #to_owned_struct
+            #visit
+            #len
+            #field_names
+            #alias
+        }
+    }
+
+    /// Generates the `FIELD_NAMES` associated constant, which lists the names
+    /// of the fields in declaration order.
+    fn expand_field_names(&self, lt: &TokenStream) -> TokenStream {
+        let vis = &self.vis;
+        let name = utils::global_associated_struct_name(&self.name);
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_usage = iter::once(lt.clone()).chain(self.generics_usage());
+
+        let where_clause = self.expand_where_clause(lt);
+
+        let field_names = self.fields.iter().map(|f| f.name.to_lit_str());
+
+        quote! {
+            impl< #( #generics_declaration ),* > #name< #( #generics_usage ),* >
+            #where_clause
+            {
+                #[allow(dead_code)]
+                #vis const FIELD_NAMES: &'static [&'static str] = &[ #( #field_names ),* ];
+            }
+        }
+    }
+
+    /// Generates the `len`, `is_empty` and `into_inner_vec` methods for
+    /// single-field tuple structs. They are available only when the butchered
+    /// field implements `Len` or `IntoVec`.
+    fn expand_len(&self, lt: &TokenStream) -> TokenStream {
+        let field = match (&self.kind, self.fields.as_slice()) {
+            (StructKind::Tupled, [field]) => field,
+            _ => return TokenStream::new(),
+        };
+
+        let krate = &self.krate;
+        let vis = &self.vis;
+        let name = utils::global_associated_struct_name(&self.name);
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_usage = iter::once(lt.clone()).chain(self.generics_usage());
+
+        let where_clause = self.expand_where_clause(lt);
+
+        let (_, ty, _) = field.associated_main_struct_data(lt);
+
+        quote! {
+            impl< #( #generics_declaration ),* > #name< #( #generics_usage ),* >
+            #where_clause
+            {
+                #[allow(dead_code)]
+                #vis fn len(&self) -> usize
+                where
+                    #ty: #krate::len::Len,
+                {
+                    #krate::len::Len::len(&self.0)
+                }
+
+                #[allow(dead_code)]
+                #vis fn is_empty(&self) -> bool
+                where
+                    #ty: #krate::len::Len,
+                {
+                    #krate::len::Len::is_empty(&self.0)
+                }
+
+                #[allow(dead_code)]
+                #vis fn into_inner_vec(self) -> ::std::vec::Vec<<#ty as #krate::into_vec::IntoVec>::Item>
+                where
+                    #ty: #krate::into_vec::IntoVec,
+                {
+                    #krate::into_vec::IntoVec::into_vec(self.0)
+                }
+            }
+        }
+    }
+
+    fn expand_visit(&self, lt: &TokenStream) -> TokenStream {
+        let krate = &self.krate;
+        let name = utils::global_associated_struct_name(&self.name);
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_usage = iter::once(lt.clone()).chain(self.generics_usage());
+
+        let where_clause = self.expand_where_clause(lt);
+
+        let fields = self.fields.iter().map(|f| &f.name);
+        let field_names = self.fields.iter().map(|f| f.name.to_lit_str());
+
+        quote! {
+            impl< #( #generics_declaration ),* > #krate::visit::ButcherVisit for #name< #( #generics_usage ),* >
+            #where_clause
+            {
+                fn visit_fields<__ButcherVisitor: #krate::visit::FieldVisitor>(&self, visitor: &mut __ButcherVisitor) {
+                    #( visitor.visit(#field_names, &self.#fields); )*
+                }
+            }
         }
     }
 
+    fn expand_reborrow(&self, lt: &TokenStream) -> TokenStream {
+        if !self.fields.iter().all(Field::is_reborrowable) {
+            return TokenStream::new();
+        }
+
+        let reborrowed_lt = fresh_lifetime("reborrow", &self.generics_for_butchered);
+
+        let vis = &self.vis;
+        let name = utils::global_associated_struct_name(&self.name);
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_usage = self.generics_usage();
+        let generics_for_input = iter::once(lt.clone()).chain(generics_usage.clone());
+        let generics_for_output = iter::once(reborrowed_lt.clone()).chain(generics_usage);
+
+        let where_clause = self.expand_where_clause(lt);
+
+        let destructured = self.destructure_butchered_struct();
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| f.name.expand_as_pattern_identifier());
+        let reborrowed_fields = self.fields.iter().map(|f| {
+            let field = f.name.expand_as_pattern_identifier();
+            f.reborrow_expr(&field)
+        });
+
+        let reborrowed = match self.kind {
+            StructKind::Named => quote! {
+                #name { #( #fields: #reborrowed_fields ),* }
+            },
+            StructKind::Tupled => quote! {
+                #name( #( #reborrowed_fields ),* )
+            },
+        };
+
+        quote! {
+            impl< #( #generics_declaration ),* > #name< #( #generics_for_input ),* >
+            #where_clause
+            {
+                #[allow(dead_code)]
+                #vis fn reborrow< #reborrowed_lt >(&#reborrowed_lt self) -> #name< #( #generics_for_output ),* > {
+                    let #destructured = self;
+                    #reborrowed
+                }
+            }
+        }
+    }
+
+    fn expand_to_owned_struct(&self, lt: &TokenStream) -> TokenStream {
+        let vis = &self.vis;
+        let name = &self.name;
+        let butchered_name = utils::global_associated_struct_name(&self.name);
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_usage = self.generics_usage();
+        let generics_for_butchered = iter::once(lt.clone()).chain(generics_usage.clone());
+
+        let where_clause = self.expand_where_clause(lt);
+
+        let destructured = self.destructure_butchered_struct();
+        let cloned_fields = self.fields.iter().map(|f| {
+            let field = f.name.expand_as_pattern_identifier();
+            quote! { ::std::clone::Clone::clone(#field) }
+        });
+        let own_each_field = self.own_each_field(lt, cloned_fields, false);
+        let initial_struct = self.recreate_initial_struct();
+
+        quote! {
+            #[allow(deprecated)]
+            impl< #( #generics_declaration ),* > #butchered_name< #( #generics_for_butchered ),* >
+            #where_clause
+            {
+                #[allow(dead_code)]
+                #vis fn to_owned_struct(&self) -> #name< #( #generics_usage ),* > {
+                    let #destructured = self;
+                    #own_each_field;
+                    #initial_struct
+                }
+            }
+        }
+    }
+
+    fn expand_cow_conversions(&self, lt: &TokenStream) -> TokenStream {
+        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let generics_declaration = quote! { < #( #generics_declaration ),* > };
+
+        let name = &self.name;
+        let generics_usage = self.generics_usage();
+        let self_type = quote! { #name< #( #generics_usage ),* > };
+
+        let predicates = &self.predicates;
+        let where_clause = quote! { where #( #predicates ),* };
+
+        expand_cow_conversions(&generics_declaration, &self_type, &where_clause, lt)
+    }
+
     fn expand_butchered_struct(&self, lt: &TokenStream) -> TokenStream {
         let vis = &self.vis;
         let name = utils::global_associated_struct_name(&self.name);
@@ -112,9 +326,8 @@ impl ButcheredStruct {
 
         let rest = self.fields_with_where_clause(lt);
 
-        // TODO: Add handling for where clause provided in initial struct declaration
         quote! {
-            #[derive(Clone)]
+            #[derive(::std::clone::Clone)]
             #vis struct #name < #( #generics ),* >
             #rest
         }
@@ -137,7 +350,12 @@ impl ButcheredStruct {
     }
 
     fn expand_where_clause(&self, lt: &TokenStream) -> TokenStream {
-        let where_clause_items = self.fields.iter().flat_map(|f| f.where_clause_items(lt));
+        let where_clause_items = dedup_predicates(
+            self.fields
+                .iter()
+                .flat_map(|f| f.where_clause_items(lt))
+                .chain(self.predicates.iter().map(|p| quote! { #p })),
+        );
         quote! {
             where
                 #( #where_clause_items ),*
@@ -175,30 +393,56 @@ impl ButcheredStruct {
     }
 
     fn expand_butchered_struct_trait(&self, lt: &TokenStream) -> TokenStream {
-        let generics_declaration = iter::once(lt.clone()).chain(self.generics_declaration(lt));
+        let krate = &self.krate;
+        let generics_declaration = iter::once(lt.clone())
+            .chain(self.generics_declaration(lt))
+            .collect::<Vec<_>>();
 
         let name = &self.name;
         let generics_usage = self.generics_usage();
-        let where_clause = &self.where_clause_for_butchered;
+        let self_type = quote! { #name< #( #generics_usage ),* > };
+        let predicates = self.predicates.iter().map(|p| quote! { #p }).chain(
+            self.fields
+                .iter()
+                .flat_map(|f| f.required_lifetime_bounds(lt)),
+        );
+        // The fast path copies the whole struct at once.
+        let predicates = if self.copies_at_once() {
+            predicates
+                .chain(iter::once(quote! { #self_type: ::std::marker::Copy }))
+                .collect::<Vec<_>>()
+        } else {
+            predicates.collect()
+        };
         let output_type = utils::global_associated_struct_name(&self.name);
-        let generics_for_output = iter::once(lt.clone()).chain(generics_usage.clone());
+        let generics_for_output = iter::once(lt.clone()).chain(self.generics_usage());
 
         let borrowed_arm = self.borrowed_match_arm(lt);
         let owned_arm = self.owned_match_arm(lt);
 
         let destructured = self.destructure_butchered_struct();
-        let own_each_field = self.own_each_field(lt);
+        let field_names = self
+            .fields
+            .iter()
+            .map(|f| f.name.expand_as_pattern_identifier());
+        let own_each_field = self.own_each_field(lt, field_names.clone(), false);
+        let try_own_each_field = self.own_each_field(lt, field_names, true);
         let initial_struct = self.recreate_initial_struct();
+        let butchered_type = quote! { #output_type < #( #generics_for_output ),* > };
 
         quote! {
+            // Deprecated fields are accessed when the struct is destructured
+            // or recreated.
+            #[allow(deprecated)]
             impl< #( #generics_declaration ),* >
-                butcher::Butcher<#lt> for
-                #name< #( #generics_usage ),* >
-            #where_clause
+                #krate::Butcher<#lt> for
+                #self_type
+            where
+                #( #predicates ),*
             {
-                type Output = #output_type < #( #generics_for_output ),* >;
+                type Output = #butchered_type;
 
-                fn butcher(this: std::borrow::Cow<#lt, Self>) -> Self::Output {
+                fn butcher(this: ::std::borrow::Cow<#lt, Self>) -> Self::Output {
                     match this {
                         #borrowed_arm,
                         #owned_arm,
@@ -210,6 +454,38 @@ impl ButcheredStruct {
                     #own_each_field;
                     #initial_struct
                 }
+
+                fn try_unbutcher(this: Self::Output) -> ::std::result::Result<Self, #krate::UnbutcherError> {
+                    let #destructured = this;
+                    #try_own_each_field;
+                    ::std::result::Result::Ok(#initial_struct)
+                }
+            }
+
+            impl< #( #generics_declaration ),* >
+                ::std::convert::TryFrom<#butchered_type> for
+                #self_type
+            where
+                #( #predicates ),*
+            {
+                type Error = #krate::UnbutcherError;
+
+                fn try_from(this: #butchered_type) -> ::std::result::Result<Self, Self::Error> {
+                    <Self as #krate::Butcher<#lt>>::try_unbutcher(this)
+                }
+            }
+
+            // The butchered fields can't be turned back into a reference to
+            // the initial struct, so the resulting `Cow` is always owned.
+            impl< #( #generics_declaration ),* >
+                ::std::convert::From<#butchered_type> for
+                ::std::borrow::Cow<#lt, #self_type>
+            where
+                #( #predicates ),*
+            {
+                fn from(this: #butchered_type) -> Self {
+                    ::std::borrow::Cow::Owned(<#self_type as #krate::Butcher<#lt>>::unbutcher(this))
+                }
             }
         }
     }
@@ -220,8 +496,8 @@ impl ButcheredStruct {
     ) -> impl Iterator<Item = TokenStream> + 'a {
         self.generics_for_butchered
             .iter()
-            .map(move |param| match param {
-                GenericParam::Type(tp) => quote! { #tp: #lt + Clone },
+            .map(move |param| match strip_generic_param(param) {
+                GenericParam::Type(tp) => quote! { #tp: #lt + ::std::clone::Clone },
                 GenericParam::Lifetime(ld) => quote! { #ld: #lt },
                 GenericParam::Const(cp) => quote! { #cp },
             })
@@ -231,11 +507,33 @@ impl ButcheredStruct {
         self.generics_for_butchered.iter().map(|param| match param {
             GenericParam::Type(TypeParam { ident, .. }) => quote! { #ident },
             GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => quote! { #lifetime },
-            GenericParam::Const(_) => todo!(),
+            GenericParam::Const(ConstParam { ident, .. }) => quote! { #ident },
         })
     }
 
+    /// Returns whether the struct is `Copy` and every field uses the copy
+    /// method.
+    ///
+    /// In this case, the borrowed struct is copied at once and then butchered
+    /// as an owned struct. The struct must be `Copy`, as its `Clone`
+    /// implementation may differ from cloning each field.
+    fn copies_at_once(&self) -> bool {
+        self.is_copy && self.fields.iter().all(Field::is_copied)
+    }
+
     fn borrowed_match_arm(&self, lt: &TokenStream) -> TokenStream {
+        if self.copies_at_once() {
+            let fields = self.fields_pattern();
+            let return_expr = self.owned_return_expr(lt);
+
+            return quote! {
+                ::std::borrow::Cow::Borrowed(this) => {
+                    let #fields = *this;
+                    #return_expr
+                }
+            };
+        }
+
         let pattern = self.borrowed_pattern();
         let return_expr = self.borrowed_return_expr(lt);
 
@@ -248,11 +546,12 @@ impl ButcheredStruct {
         let fields = self.fields_pattern();
 
         quote! {
-            std::borrow::Cow::Borrowed( #fields )
+            ::std::borrow::Cow::Borrowed( #fields )
         }
     }
 
     fn borrowed_return_expr(&self, lt: &TokenStream) -> TokenStream {
+        let krate = &self.krate;
         let return_type_name = utils::global_associated_struct_name(&self.name);
         let fields = self
             .fields
@@ -271,14 +570,14 @@ impl ButcheredStruct {
             StructKind::Named => {
                 quote! {
                     #return_type_name {
-                        #( #fields: <#associated_structs as butcher::methods::ButcherField<#lt, #associated_struct_types>>::from_borrowed( #fields_2 ) ),*
+                        #( #fields: <#associated_structs as #krate::methods::ButcherField<#lt, #associated_struct_types>>::from_borrowed( #fields_2 ) ),*
                     }
                 }
             }
 
             StructKind::Tupled => quote! {
                 #return_type_name(
-                    #( <#associated_structs as butcher::methods::ButcherField<#lt, #associated_struct_types>>::from_borrowed( #fields_2 ) ),*
+                    #( <#associated_structs as #krate::methods::ButcherField<#lt, #associated_struct_types>>::from_borrowed( #fields_2 ) ),*
                 )
             },
         }
@@ -297,11 +596,12 @@ impl ButcheredStruct {
         let fields = self.fields_pattern();
 
         quote! {
-            std::borrow::Cow::Owned( #fields )
+            ::std::borrow::Cow::Owned( #fields )
         }
     }
 
     fn owned_return_expr(&self, lt: &TokenStream) -> TokenStream {
+        let krate = &self.krate;
         let return_type_name = utils::global_associated_struct_name(&self.name);
         let fields = self
             .fields
@@ -321,14 +621,14 @@ impl ButcheredStruct {
             StructKind::Named => {
                 quote! {
                     #return_type_name {
-                        #( #fields: <#associated_structs as butcher::methods::ButcherField<#lt, #associated_struct_types>>::from_owned( #fields_2 ) ),*
+                        #( #fields: <#associated_structs as #krate::methods::ButcherField<#lt, #associated_struct_types>>::from_owned( #fields_2 ) ),*
                     }
                 }
             }
 
             StructKind::Tupled => quote! {
                 #return_type_name(
-                    #( <#associated_structs as butcher::methods::ButcherField<#lt, #associated_struct_types>>::from_owned( #fields ) ),*
+                    #( <#associated_structs as #krate::methods::ButcherField<#lt, #associated_struct_types>>::from_owned( #fields ) ),*
                 )
             },
         }
@@ -375,14 +675,23 @@ impl ButcheredStruct {
         }
     }
 
-    fn own_each_field(&self, lt: &TokenStream) -> TokenStream {
+    fn own_each_field(
+        &self,
+        lt: &TokenStream,
+        values: impl Iterator<Item = TokenStream>,
+        fallible: bool,
+    ) -> TokenStream {
+        let krate = &self.krate;
+        let (unbutcher, question_mark) = if fallible {
+            (quote! { try_unbutcher }, quote! { ? })
+        } else {
+            (quote! { unbutcher }, TokenStream::new())
+        };
         let names = self
             .fields
             .iter()
             .map(|f| f.name.expand_as_pattern_identifier());
 
-        let names2 = names.clone();
-
         let associated_structs = self
             .fields
             .iter()
@@ -394,7 +703,7 @@ impl ButcheredStruct {
         quote! {
             let ( #( #names ),* ) =
                 ( #(
-                    < #associated_structs as butcher::methods::ButcherField< #lt, #associated_struct_types >>::unbutcher( #names2 )
+                    < #associated_structs as #krate::methods::ButcherField< #lt, #associated_struct_types >>::#unbutcher( #values ) #question_mark
                 ),* );
         }
     }
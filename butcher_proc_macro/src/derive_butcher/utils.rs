@@ -1,12 +1,18 @@
-use std::iter::{self, FromIterator};
+use std::{
+    collections::HashSet,
+    iter::{self, FromIterator},
+};
 
 use syn::{
-    punctuated::Punctuated, AngleBracketedGenericArguments, Binding, Constraint, DeriveInput,
-    GenericArgument, GenericParam, Ident, LifetimeDef, ParenthesizedGenericArguments, Path,
-    PathArguments, PathSegment, ReturnType, Token, Type, TypeParam, TypeParamBound, TypePath,
+    parse_quote, punctuated::Punctuated, AngleBracketedGenericArguments, Binding, ConstParam,
+    Constraint, DeriveInput, GenericArgument, GenericParam, Generics, Ident, Lifetime, LifetimeDef,
+    ParenthesizedGenericArguments, Path, PathArguments, PathSegment, ReturnType, Token, Type,
+    TypeParam, TypeParamBound, TypePath, Visibility, WherePredicate,
 };
 
-use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use proc_macro2::{Span, TokenStream, TokenTree};
 
 pub(super) trait ReplaceSelf {
     fn replace(&mut self, rep: &Type);
@@ -198,6 +204,251 @@ impl ReplaceSelf for TokenStream {
     fn replace(&mut self, _rep: &Type) {}
 }
 
+/// Generates the conversions from an owned value and from a reference into a
+/// `Cow`, so that `Butcher::butcher_into` accepts any of them.
+pub(super) fn expand_cow_conversions(
+    generics_declaration: &TokenStream,
+    self_type: &TokenStream,
+    where_clause: &TokenStream,
+    lt: &TokenStream,
+) -> TokenStream {
+    quote! {
+        impl #generics_declaration ::std::convert::From<#self_type> for ::std::borrow::Cow<#lt, #self_type>
+        #where_clause
+        {
+            fn from(this: #self_type) -> Self {
+                ::std::borrow::Cow::Owned(this)
+            }
+        }
+
+        impl #generics_declaration ::std::convert::From<& #lt #self_type> for ::std::borrow::Cow<#lt, #self_type>
+        #where_clause
+        {
+            fn from(this: & #lt #self_type) -> Self {
+                ::std::borrow::Cow::Borrowed(this)
+            }
+        }
+    }
+}
+
+/// Generates the type alias requested with `#[butcher(alias = "...")]`, if
+/// any. The alias takes the same generic parameters as the butchered type.
+pub(super) fn expand_alias(
+    vis: &Visibility,
+    alias: Option<&Ident>,
+    butchered_name: &Ident,
+    generics: &[GenericParam],
+    lt: &TokenStream,
+) -> TokenStream {
+    let alias = match alias {
+        Some(alias) => alias,
+        None => return TokenStream::new(),
+    };
+
+    let generics_declaration = generics.iter().map(strip_generic_param);
+    let generics_usage = generics.iter().map(|param| match param {
+        GenericParam::Type(TypeParam { ident, .. }) => quote! { #ident },
+        GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => quote! { #lifetime },
+        GenericParam::Const(ConstParam { ident, .. }) => quote! { #ident },
+    });
+
+    quote! {
+        #[allow(dead_code)]
+        #vis type #alias < #lt #( , #generics_declaration )* > = #butchered_name < #lt #( , #generics_usage )* >;
+    }
+}
+
+/// Collects every successfully parsed item. If some items could not be parsed,
+/// then their errors are combined, so that they are all reported at once.
+pub(super) fn collect_combining_errors<T>(
+    items: impl Iterator<Item = Result<T, syn::Error>>,
+) -> Result<Vec<T>, syn::Error> {
+    let mut parsed = Vec::new();
+    let mut error: Option<syn::Error> = None;
+
+    for item in items {
+        match (item, error.as_mut()) {
+            (Ok(v), None) => parsed.push(v),
+            (Ok(_), Some(_)) => {}
+            (Err(e), Some(main_err)) => main_err.combine(e),
+            (Err(e), None) => error = Some(e),
+        }
+    }
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(parsed),
+    }
+}
+
+/// Returns every predicate which applies on the generics of the initial type,
+/// whether it is written inline in the generics declaration or in the where
+/// clause.
+pub(super) fn container_predicates(generics: &Generics) -> Vec<WherePredicate> {
+    let inline_predicates = generics.params.iter().filter_map(|param| match param {
+        GenericParam::Type(TypeParam { ident, bounds, .. }) if !bounds.is_empty() => {
+            Some(parse_quote! { #ident: #bounds })
+        }
+        GenericParam::Lifetime(LifetimeDef {
+            lifetime, bounds, ..
+        }) if !bounds.is_empty() => Some(parse_quote! { #lifetime: #bounds }),
+        _ => None,
+    });
+
+    let where_predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter().cloned());
+
+    inline_predicates.chain(where_predicates).collect()
+}
+
+/// Removes the duplicated predicates, keeping the first occurrence of each
+/// of them. Multiple fields may require the same bound.
+pub(super) fn dedup_predicates(
+    predicates: impl IntoIterator<Item = TokenStream>,
+) -> Vec<TokenStream> {
+    let mut seen = HashSet::new();
+
+    predicates
+        .into_iter()
+        .filter(|predicate| seen.insert(predicate.to_string()))
+        .collect()
+}
+
+/// Removes the bounds and the default value of a generic parameter, so that it
+/// can be declared in an impl block.
+pub(super) fn strip_generic_param(param: &GenericParam) -> GenericParam {
+    match param {
+        GenericParam::Type(TypeParam { ident, .. }) => parse_quote! { #ident },
+        GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => parse_quote! { #lifetime },
+        GenericParam::Const(ConstParam { ident, ty, .. }) => parse_quote! { const #ident: #ty },
+    }
+}
+
+/// The generic parameters declared by the initial struct or enum, sorted by
+/// kind.
+pub(super) struct ContainerGenerics {
+    pub types: HashSet<Ident>,
+    pub lifetimes: HashSet<Lifetime>,
+    pub consts: Vec<ConstParam>,
+}
+
+impl ContainerGenerics {
+    pub(super) fn from(generics: &Generics) -> ContainerGenerics {
+        let mut types = HashSet::new();
+        let mut lifetimes = HashSet::new();
+        let mut consts = Vec::new();
+
+        generics.params.iter().for_each(|g| match g {
+            GenericParam::Type(t) => {
+                types.insert(t.ident.clone());
+            }
+            GenericParam::Lifetime(lt) => {
+                lifetimes.insert(lt.lifetime.clone());
+            }
+            GenericParam::Const(c) => consts.push(c.clone()),
+        });
+
+        ContainerGenerics {
+            types,
+            lifetimes,
+            consts,
+        }
+    }
+}
+
+/// Returns the lifetime `'name`, or a variant of it which is not declared in
+/// `generics`, so that it does not collide with a lifetime of the initial
+/// type.
+pub(super) fn fresh_lifetime(name: &str, generics: &[GenericParam]) -> TokenStream {
+    let declared = generics
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => {
+                Some(lifetime.ident.to_string())
+            }
+            GenericParam::Type(_) | GenericParam::Const(_) => None,
+        })
+        .collect::<HashSet<_>>();
+
+    let mut name = name.to_string();
+    while declared.contains(&name) {
+        name.push('_');
+    }
+
+    let lt = Lifetime::new(&format!("'{}", name), Span::call_site());
+    quote! { #lt }
+}
+
+/// The keywords which can be used as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// The keywords which can't be used as raw identifiers.
+const RESERVED_PATH_SEGMENTS: &[&str] = &["crate", "self", "super"];
+
+/// Converts `ident`, written in camel case, to snake case.
+///
+/// Keywords are returned as raw identifiers, except the ones which can't be,
+/// which get a trailing underscore.
+pub(super) fn snake_case_ident(ident: &Ident) -> Ident {
+    let name = ident.to_string();
+    let chars = name.trim_start_matches("r#").chars().collect::<Vec<_>>();
+
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let previous = if i > 0 { chars.get(i - 1) } else { None };
+            let next = chars.get(i + 1);
+
+            let ends_word = matches!(previous, Some(p) if p.is_lowercase() || p.is_numeric());
+            let ends_acronym = matches!(previous, Some(p) if p.is_uppercase())
+                && matches!(next, Some(n) if n.is_lowercase());
+
+            if ends_word || ends_acronym {
+                snake.push('_');
+            }
+
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(*c);
+        }
+    }
+
+    if RESERVED_PATH_SEGMENTS.contains(&snake.as_str()) {
+        snake.push('_');
+        Ident::new(&snake, ident.span())
+    } else if KEYWORDS.contains(&snake.as_str()) {
+        format_ident!("r#{}", snake, span = ident.span())
+    } else {
+        Ident::new(&snake, ident.span())
+    }
+}
+
+/// Returns every identifier contained in `tokens`. The name of the lifetimes
+/// are included as well.
+pub(super) fn mentioned_idents(tokens: TokenStream) -> HashSet<Ident> {
+    let mut idents = HashSet::new();
+
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident);
+            }
+            TokenTree::Group(group) => idents.extend(mentioned_idents(group.stream())),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+    }
+
+    idents
+}
+
 pub(super) fn create_type_signature(input: &DeriveInput) -> Type {
     let name = input.ident.clone();
     let params = input.generics.params.iter().cloned();
@@ -256,7 +507,9 @@ fn arguments_from_params(
 
 fn generic_param(generic_param: GenericParam) -> Option<GenericArgument> {
     match generic_param {
-        GenericParam::Type(TypeParam { ident, .. }) => {
+        // A const argument which is a single identifier is parsed as a type.
+        GenericParam::Type(TypeParam { ident, .. })
+        | GenericParam::Const(ConstParam { ident, .. }) => {
             let segments = Punctuated::from_iter(iter::once(PathSegment {
                 ident,
                 arguments: PathArguments::None,
@@ -276,8 +529,6 @@ fn generic_param(generic_param: GenericParam) -> Option<GenericArgument> {
         GenericParam::Lifetime(LifetimeDef { lifetime, .. }) => {
             Some(GenericArgument::Lifetime(lifetime))
         }
-
-        GenericParam::Const(_) => None,
     }
 }
 
@@ -351,11 +602,6 @@ mod create_type_signature {
 // matching rule $($_:tt)+ eats the whole remaining input, including $right.
 #[cfg(test)]
 macro_rules! test_generic_param {
-    (None, $($left:tt)+ $(,)?) => {
-        let tmp: GenericParam = parse_quote! { $($left)+ };
-        assert!(generic_param(tmp).is_none());
-    };
-
     ($right:tt, $($left:tt)+ $(,)?) => {
         let input: GenericParam = syn::parse_quote! { $($left)+ };
         let left = generic_param(input);
@@ -368,8 +614,6 @@ macro_rules! test_generic_param {
 mod generic_param {
     use super::*;
 
-    use syn::parse_quote;
-
     #[test]
     fn handles_type() {
         test_generic_param!(T, T);
@@ -383,7 +627,132 @@ mod generic_param {
     }
 
     #[test]
-    fn does_not_hangle_consts() {
-        test_generic_param!(None, const LENGTH: usize);
+    fn handles_consts() {
+        test_generic_param!(LENGTH, const LENGTH: usize);
+        test_generic_param!(LENGTH, const LENGTH: usize = 4);
+    }
+}
+
+#[cfg(test)]
+mod snake_case_ident {
+    use super::*;
+
+    fn snake_case(name: &str) -> String {
+        snake_case_ident(&Ident::new(name, Span::call_site())).to_string()
+    }
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(snake_case("PageLoad"), "page_load");
+        assert_eq!(snake_case("Click"), "click");
+        assert_eq!(snake_case("V2Beta"), "v2_beta");
+    }
+
+    #[test]
+    fn acronyms() {
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("GetURL"), "get_url");
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(snake_case("Type"), "r#type");
+        assert_eq!(snake_case("SelfRef"), "self_ref");
+        assert_eq!(snake_case("Crate"), "crate_");
+    }
+}
+
+#[cfg(test)]
+mod container_predicates {
+    use super::*;
+
+    #[test]
+    fn merges_inline_bounds_and_where_clause() {
+        let input: DeriveInput = parse_quote! {
+            struct Foo<'a, 'b: 'a, T: Clone = String, U>
+            where
+                U: Into<T>,
+            {
+                t: &'a T,
+                u: &'b U,
+            }
+        };
+
+        let left = container_predicates(&input.generics);
+        let right: Punctuated<WherePredicate, Token![,]> = parse_quote! {
+            'b: 'a,
+            T: Clone,
+            U: Into<T>
+        };
+        let right = right.into_iter().collect::<Vec<_>>();
+
+        assert_eq!(
+            quote! { #( #left ),* }.to_string(),
+            quote! { #( #right ),* }.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod dedup_predicates {
+    use super::*;
+
+    #[test]
+    fn keeps_first_occurrences() {
+        let predicates = vec![
+            quote! { T: Clone },
+            quote! { U: Copy },
+            quote! { T : Clone },
+            quote! { T: Clone + 'cow },
+        ];
+
+        let left = dedup_predicates(predicates);
+        let left = quote! { #( #left ),* };
+        let right = quote! { T: Clone, U: Copy, T: Clone + 'cow };
+
+        assert_eq_tt!(left, right);
+    }
+}
+
+#[cfg(test)]
+mod strip_generic_param {
+    use super::*;
+
+    #[test]
+    fn removes_bounds_and_defaults() {
+        let input: GenericParam = parse_quote! { T: Clone = String };
+        let left = strip_generic_param(&input);
+        let right: GenericParam = parse_quote! { T };
+        assert_eq_tt!(left, right);
+
+        let input: GenericParam = parse_quote! { 'a: 'b };
+        let left = strip_generic_param(&input);
+        let right: GenericParam = parse_quote! { 'a };
+        assert_eq_tt!(left, right);
+    }
+}
+
+#[cfg(test)]
+mod fresh_lifetime {
+    use super::*;
+
+    #[test]
+    fn keeps_unused_name() {
+        let generics: Generics = parse_quote! { <'a, T> };
+        let params = generics.params.into_iter().collect::<Vec<_>>();
+
+        let left = fresh_lifetime("cow", &params);
+        let right = quote! { 'cow };
+        assert_eq_tt!(left, right);
+    }
+
+    #[test]
+    fn avoids_declared_lifetimes() {
+        let generics: Generics = parse_quote! { <'cow, 'cow_: 'cow, T> };
+        let params = generics.params.into_iter().collect::<Vec<_>>();
+
+        let left = fresh_lifetime("cow", &params);
+        let right = quote! { 'cow__ };
+        assert_eq_tt!(left, right);
     }
 }
@@ -1,10 +1,10 @@
 use std::fmt::{Formatter, Result as FmtResult};
 
-use syn::Ident;
+use syn::{Ident, LitStr};
 
 use quote::{format_ident, quote, IdentFragment, ToTokens, TokenStreamExt};
 
-use proc_macro2::{Literal, TokenStream};
+use proc_macro2::{Literal, Span, TokenStream};
 
 #[derive(Clone, Debug, PartialEq)]
 pub(crate) enum FieldName {
@@ -30,6 +30,13 @@ impl FieldName {
             }
         }
     }
+
+    /// Returns the name of the field as a string literal, without the `r#`
+    /// prefix of raw identifiers.
+    pub(crate) fn to_lit_str(&self) -> LitStr {
+        let name = self.to_token_stream().to_string();
+        LitStr::new(name.trim_start_matches("r#"), Span::call_site())
+    }
 }
 
 impl From<Ident> for FieldName {